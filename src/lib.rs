@@ -1,25 +1,67 @@
 //! Monero daemon and wallet RPC.
+//!
+//! # Features
+//! - `rustls-tls` (default): use rustls for HTTPS connections.
+//! - `native-tls`: use the platform's TLS library (OpenSSL on Linux) instead. Takes precedence over `rustls-tls` if both are enabled.
+//! - `gzip`, `brotli`: support compressed responses, enabled through the fields of the same name of [`RpcClientOptions`].
+//! - `strict`: reject responses with fields the models do not know about. Meant for catching changes in the RPC schema between daemon versions during development, not for production.
+//!   The models cover the fields returned by Monero v0.18, so responses of newer daemons may be rejected. The `credits` and `top_hash` fields
+//!   of daemons charging for RPC access are always accepted and dropped. Responses that are only read through a private helper type,
+//!   for instance to return a single field, are not checked.
+//!
+//! Building with `default-features = false` and neither TLS feature leaves out TLS support entirely: only `http://` addresses work
+//! and requests to `https://` addresses fail. This suits a node on localhost or on a trusted network.
 
 #[macro_use]
 mod util;
-mod models;
+pub mod models;
 
 pub use self::{models::*, util::*};
 
+/// Convenience re-exports: the clients, shared types, and the per-client model modules.
+///
+/// ```
+/// use monero_rpc::prelude::*;
+///
+/// fn balance(data: &wallet::BalanceData) -> u64 {
+///     data.unlocked_balance
+/// }
+/// ```
+pub mod prelude {
+    pub use crate::{
+        models::{common::*, daemon, wallet},
+        util::*,
+        CachedDaemonClient, Cancelled, DaemonClient, GetBlockHeaderSelector, GetBlockSelector,
+        RegtestDaemonClient, ResilientDaemonClient, RpcClient, RpcResult, UnsupportedByDaemon,
+        WalletClient,
+    };
+}
+
 use async_trait::async_trait;
+use futures::{
+    future::join_all,
+    stream::{self, Stream},
+};
 use jsonrpc_core::types::{Id, *};
-use monero::{cryptonote::hash::Hash as CryptoNoteHash, util::address::PaymentId, Address};
+use monero::{
+    cryptonote::hash::Hash as CryptoNoteHash,
+    util::address::{AddressType, PaymentId},
+    Address,
+};
 use serde::{de::IgnoredAny, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::{json, Value};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     convert::TryFrom,
     fmt::Debug,
+    future::Future,
     iter::{empty, once},
     num::NonZeroU64,
     ops::{Bound, Deref, RangeBounds, RangeInclusive},
-    sync::Arc,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
 };
+use tokio_util::sync::CancellationToken;
 use tracing::*;
 use uuid::Uuid;
 
@@ -61,13 +103,62 @@ trait JsonRpcCaller: Debug + Send + Sync + 'static {
         &self,
         method: &'static str,
         params: RpcParams,
+        timeout: Option<Duration>,
     ) -> anyhow::Result<jsonrpc_core::Result<Value>>;
+
+    /// Send several calls as a single JSON-RPC batch. Results are returned in the order of `calls`.
+    async fn call_batch(
+        &self,
+        calls: Vec<(&'static str, RpcParams)>,
+    ) -> anyhow::Result<Vec<jsonrpc_core::Result<Value>>>;
+
+    /// Call an endpoint that is not part of the JSON-RPC interface, such as `/get_transactions`, with `params` as the JSON body.
+    async fn call_other(&self, path: &'static str, params: RpcParams) -> anyhow::Result<Value>;
 }
 
 #[derive(Debug)]
 struct RemoteCaller {
     http_client: reqwest::Client,
     addr: String,
+    jsonrpc_version: Option<String>,
+    send_id: bool,
+}
+
+impl RemoteCaller {
+    fn method_call(method: &'static str, params: RpcParams) -> MethodCall {
+        MethodCall {
+            jsonrpc: Some(Version::V2),
+            method: method.to_string(),
+            params: params.into(),
+            id: Id::Str(Uuid::new_v4().to_string()),
+        }
+    }
+
+    /// Serialize a call, applying the configured `jsonrpc` version and `id` presence.
+    fn envelope(&self, method_call: &MethodCall, send_id: bool) -> Value {
+        let mut v = serde_json::to_value(method_call).unwrap();
+        if let Value::Object(fields) = &mut v {
+            match &self.jsonrpc_version {
+                Some(version) => fields.insert("jsonrpc".into(), version.clone().into()),
+                None => fields.remove("jsonrpc"),
+            };
+            if !send_id {
+                fields.remove("id");
+            }
+        }
+        v
+    }
+
+    /// Parse a response from a server that may echo a non-standard `jsonrpc` version or no `id`.
+    fn output(mut v: Value) -> anyhow::Result<response::Output> {
+        if let Value::Object(fields) = &mut v {
+            if fields.get("jsonrpc").is_some_and(|v| v != "2.0") {
+                fields.remove("jsonrpc");
+            }
+            fields.entry("id").or_insert(Value::Null);
+        }
+        Ok(serde_json::from_value(v)?)
+    }
 }
 
 #[async_trait]
@@ -76,46 +167,363 @@ impl JsonRpcCaller for RemoteCaller {
         &self,
         method: &'static str,
         params: RpcParams,
+        timeout: Option<Duration>,
     ) -> anyhow::Result<jsonrpc_core::Result<Value>> {
         let client = self.http_client.clone();
         let uri = format!("{}/json_rpc", &self.addr);
 
-        let method_call = MethodCall {
-            jsonrpc: Some(Version::V2),
-            method: method.to_string(),
-            params: params.into(),
-            id: Id::Str(Uuid::new_v4().to_string()),
-        };
+        let method_call = Self::method_call(method, params);
 
         trace!("Sending JSON-RPC method call: {:?}", method_call);
 
+        let mut req = client
+            .post(&uri)
+            .json(&self.envelope(&method_call, self.send_id));
+        if let Some(timeout) = timeout {
+            req = req.timeout(timeout);
+        }
+
+        let rsp = Self::output(req.send().await?.json().await?)?;
+
+        trace!("Received JSON-RPC response: {:?}", rsp);
+
+        let v = jsonrpc_core::Result::<Value>::from(rsp);
+
+        Ok(v)
+    }
+
+    async fn call_other(&self, path: &'static str, params: RpcParams) -> anyhow::Result<Value> {
+        let client = self.http_client.clone();
+        let uri = format!("{}/{}", &self.addr, path);
+
+        let body = match Params::from(params) {
+            Params::Map(v) => Value::Object(v),
+            Params::Array(v) => Value::Array(v),
+            Params::None => json!({}),
+        };
+
+        trace!("Sending request to {}: {:?}", path, body);
+
         let rsp = client
             .post(&uri)
-            .json(&method_call)
+            .json(&body)
             .send()
             .await?
-            .json::<response::Output>()
+            .error_for_status()?
+            .json::<Value>()
             .await?;
 
-        trace!("Received JSON-RPC response: {:?}", rsp);
+        trace!("Received response from {}: {:?}", path, rsp);
 
-        let v = jsonrpc_core::Result::<Value>::from(rsp);
+        Ok(rsp)
+    }
 
-        Ok(v)
+    async fn call_batch(
+        &self,
+        calls: Vec<(&'static str, RpcParams)>,
+    ) -> anyhow::Result<Vec<jsonrpc_core::Result<Value>>> {
+        let client = self.http_client.clone();
+        let uri = format!("{}/json_rpc", &self.addr);
+
+        let method_calls = calls
+            .into_iter()
+            .map(|(method, params)| Self::method_call(method, params))
+            .collect::<Vec<_>>();
+        let ids = method_calls
+            .iter()
+            .map(|c| c.id.clone())
+            .collect::<Vec<_>>();
+
+        trace!("Sending JSON-RPC batch: {:?}", method_calls);
+
+        // Batched calls always carry an id, otherwise responses could not be matched to them.
+        let request = method_calls
+            .iter()
+            .map(|c| self.envelope(c, true))
+            .collect::<Vec<_>>();
+
+        let rsp = client
+            .post(&uri)
+            .json(&request)
+            .send()
+            .await?
+            .json::<Value>()
+            .await?;
+
+        let rsp = match rsp {
+            Value::Array(v) => v
+                .into_iter()
+                .map(Self::output)
+                .collect::<Result<Vec<_>, _>>()?,
+            // Servers without batch support answer with a single error object, whose id matches none of the calls.
+            v => match Self::output(v)? {
+                response::Output::Failure(f) if !ids.contains(&f.id) => return Err(f.error.into()),
+                output => vec![output],
+            },
+        };
+
+        trace!("Received JSON-RPC batch response: {:?}", rsp);
+
+        let mut outputs = rsp
+            .into_iter()
+            .map(|v| (v.id().clone(), v))
+            .collect::<HashMap<_, _>>();
+
+        ids.into_iter()
+            .map(|id| {
+                outputs
+                    .remove(&id)
+                    .map(jsonrpc_core::Result::<Value>::from)
+                    .ok_or_else(|| anyhow::anyhow!("No response for batched call {:?}", id))
+            })
+            .collect()
+    }
+}
+
+/// Error returned by calls made through a client whose cancellation token was triggered, see `WalletClient::with_cancellation`.
+#[derive(Clone, Copy, Debug)]
+pub struct Cancelled;
+
+impl std::fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "RPC call cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Error returned by methods that need a newer RPC server than the one the client talks to.
+/// Versions are `(major, minor)`, as returned by `get_version`.
+#[derive(Clone, Copy, Debug)]
+pub struct UnsupportedByDaemon {
+    pub method: &'static str,
+    pub required: (u16, u16),
+    pub actual: (u16, u16),
+}
+
+impl std::fmt::Display for UnsupportedByDaemon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} requires RPC version {}.{}, but the server has {}.{}",
+            self.method, self.required.0, self.required.1, self.actual.0, self.actual.1
+        )
+    }
+}
+
+impl std::error::Error for UnsupportedByDaemon {}
+
+/// Inspect the JSON-RPC error a call failed with, if any.
+///
+/// ```no_run
+/// # async fn run(wallet: monero_rpc::WalletClient) -> anyhow::Result<()> {
+/// use monero_rpc::RpcResult;
+///
+/// let rsp = wallet.get_height().await;
+/// if rsp.is_rpc_not_found() {
+///     // Not a wallet RPC server.
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub trait RpcResult {
+    /// Code of the JSON-RPC error returned by the server. `None` if the call succeeded or failed for another reason, e.g. a network error.
+    fn rpc_code(&self) -> Option<i64>;
+
+    /// Whether the server does not know the called method.
+    fn is_rpc_not_found(&self) -> bool {
+        self.rpc_code() == Some(ErrorCode::MethodNotFound.code())
+    }
+}
+
+impl<T> RpcResult for anyhow::Result<T> {
+    fn rpc_code(&self) -> Option<i64> {
+        self.as_ref()
+            .err()?
+            .downcast_ref::<jsonrpc_core::Error>()
+            .map(|e| e.code.code())
     }
 }
 
 #[derive(Clone, Debug)]
-struct CallerWrapper(Arc<dyn JsonRpcCaller>);
+struct CallerWrapper {
+    caller: Arc<dyn JsonRpcCaller>,
+    network: Option<monero::Network>,
+    cancellation: Option<CancellationToken>,
+    version: Arc<Mutex<Option<(u16, u16)>>>,
+}
 
 impl CallerWrapper {
+    /// Run `f` unless the cancellation token fires first, in which case `f` is dropped, aborting its HTTP request.
+    async fn cancellable<F, T>(&self, f: F) -> anyhow::Result<T>
+    where
+        F: Future<Output = anyhow::Result<T>>,
+    {
+        match &self.cancellation {
+            None => f.await,
+            Some(token) => tokio::select! {
+                _ = token.cancelled() => Err(Cancelled.into()),
+                v = f => v,
+            },
+        }
+    }
+
+    async fn call(
+        &self,
+        method: &'static str,
+        params: RpcParams,
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<jsonrpc_core::Result<Value>> {
+        let start = Instant::now();
+        let rsp = self
+            .cancellable(self.caller.call(method, params, timeout))
+            .await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match &rsp {
+            Ok(Ok(_)) => debug!(method, duration_ms, ok = true, "RPC call"),
+            Ok(Err(e)) => debug!(
+                method,
+                duration_ms,
+                ok = false,
+                code = e.code.code(),
+                "RPC call"
+            ),
+            Err(e) => debug!(method, duration_ms, ok = false, error = %e, "RPC call"),
+        }
+
+        rsp
+    }
+
+    /// Ensure `address` belongs to the network the client was created for, if any.
+    fn check_address(&self, address: &Address) -> anyhow::Result<()> {
+        if let Some(network) = self.network {
+            if address.network != network {
+                anyhow::bail!(
+                    "Address {} is for {:?}, but the client expects {:?}",
+                    address,
+                    address.network,
+                    network
+                );
+            }
+        }
+
+        Ok(())
+    }
+
     async fn request<T>(&self, method: &'static str, params: RpcParams) -> anyhow::Result<T>
     where
         T: for<'de> Deserialize<'de> + Send + 'static,
     {
-        let c = self.0.call(method, params);
+        self.request_with_timeout(method, params, None).await
+    }
+
+    async fn request_with_timeout<T>(
+        &self,
+        method: &'static str,
+        params: RpcParams,
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<T>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        let c = self.call(method, params, timeout);
         Ok(serde_json::from_value(c.await??)?)
     }
+
+    async fn request_other<T>(&self, path: &'static str, params: RpcParams) -> anyhow::Result<T>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        let start = Instant::now();
+        let rsp = self.cancellable(self.caller.call_other(path, params)).await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match &rsp {
+            Ok(_) => debug!(method = path, duration_ms, ok = true, "RPC call"),
+            Err(e) => debug!(method = path, duration_ms, ok = false, error = %e, "RPC call"),
+        }
+
+        Ok(serde_json::from_value(rsp?)?)
+    }
+
+    /// Send one call of `method` per entry of `params` as a single batch. Failure of one call does not affect the others.
+    async fn request_batch<T>(
+        &self,
+        method: &'static str,
+        params: Vec<RpcParams>,
+    ) -> anyhow::Result<Vec<anyhow::Result<T>>>
+    where
+        T: for<'de> Deserialize<'de> + Send + 'static,
+    {
+        let calls = params.len();
+        let start = Instant::now();
+        let rsp = self
+            .cancellable(
+                self.caller
+                    .call_batch(params.into_iter().map(|p| (method, p)).collect()),
+            )
+            .await;
+        let duration_ms = start.elapsed().as_millis() as u64;
+
+        match &rsp {
+            Ok(_) => debug!(method, calls, duration_ms, ok = true, "RPC batch"),
+            Err(e) => debug!(method, calls, duration_ms, ok = false, error = %e, "RPC batch"),
+        }
+
+        Ok(rsp?
+            .into_iter()
+            .map(|rsp| Ok(serde_json::from_value(rsp?)?))
+            .collect())
+    }
+
+    /// Fail with [`UnsupportedByDaemon`] if the server's RPC version is older than `required`.
+    /// The version is fetched on first use and cached for the lifetime of the client and its clones.
+    async fn require_version(
+        &self,
+        method: &'static str,
+        required: (u16, u16),
+    ) -> anyhow::Result<()> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            version: u32,
+        }
+
+        let cached = *self.version.lock().unwrap();
+        let actual = match cached {
+            Some(v) => v,
+            None => {
+                let rsp = self.request::<Rsp>("get_version", RpcParams::None).await?;
+                let v = ((rsp.version >> 16) as u16, rsp.version as u16);
+                *self.version.lock().unwrap() = Some(v);
+                v
+            }
+        };
+
+        if actual < required {
+            return Err(UnsupportedByDaemon {
+                method,
+                required,
+                actual,
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that the endpoint knows `method`, which only exists on `expected` RPC servers.
+    async fn verify_endpoint(&self, method: &'static str, expected: &str) -> anyhow::Result<()> {
+        match self.call(method, RpcParams::None, None).await? {
+            Err(e) if e.code == ErrorCode::MethodNotFound => Err(anyhow::anyhow!(
+                "Endpoint does not know method {}, it is not a {} RPC server",
+                method,
+                expected
+            )),
+            // Any other answer, even an error, comes from the method itself.
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Base RPC client. It is useless on its own, please see the attached methods instead.
@@ -125,12 +533,72 @@ pub struct RpcClient {
 }
 
 impl RpcClient {
-    pub fn new(addr: String) -> Self {
+    /// Create a client for the RPC server at `addr`, e.g. `"http://127.0.0.1:18081"`.
+    pub fn new(addr: impl Into<String>) -> Self {
+        Self::with_options(addr, Default::default())
+    }
+
+    /// Create a client for a node on `network`. Methods taking addresses reject addresses from other networks before sending the request.
+    pub fn with_network(addr: impl Into<String>, network: monero::Network) -> Self {
+        Self::with_options(
+            addr,
+            RpcClientOptions {
+                network: Some(network),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Create a client with custom options.
+    pub fn with_options(addr: impl Into<String>, options: RpcClientOptions) -> Self {
+        Self::build(addr.into(), options, true)
+    }
+
+    /// Without `pool_connections`, every request opens a new connection instead of reusing an idle one.
+    fn build(addr: String, options: RpcClientOptions, pool_connections: bool) -> Self {
+        let RpcClientOptions {
+            network,
+            jsonrpc_version,
+            send_id,
+            #[cfg(feature = "gzip")]
+            gzip,
+            #[cfg(feature = "brotli")]
+            brotli,
+            http2_prior_knowledge,
+        } = options;
+
+        let http_client = reqwest::ClientBuilder::new();
+        #[cfg(feature = "gzip")]
+        let http_client = http_client.gzip(gzip);
+        #[cfg(feature = "brotli")]
+        let http_client = http_client.brotli(brotli);
+        let http_client = if http2_prior_knowledge {
+            http_client.http2_prior_knowledge()
+        } else {
+            http_client
+        };
+        let http_client = if pool_connections {
+            http_client
+        } else {
+            http_client.pool_max_idle_per_host(0)
+        };
+        #[cfg(feature = "native-tls")]
+        let http_client = http_client.use_native_tls();
+        #[cfg(all(feature = "rustls-tls", not(feature = "native-tls")))]
+        let http_client = http_client.use_rustls_tls();
+
         Self {
-            inner: CallerWrapper(Arc::new(RemoteCaller {
-                http_client: reqwest::ClientBuilder::new().build().unwrap(),
-                addr,
-            })),
+            inner: CallerWrapper {
+                caller: Arc::new(RemoteCaller {
+                    http_client: http_client.build().unwrap(),
+                    addr,
+                    jsonrpc_version,
+                    send_id,
+                }),
+                network,
+                cancellation: None,
+                version: Default::default(),
+            },
         }
     }
 
@@ -163,13 +631,43 @@ impl Deref for RegtestDaemonClient {
     }
 }
 
+#[derive(Clone, Debug)]
 pub enum GetBlockHeaderSelector {
     Last,
     Hash(BlockHash),
     Height(u64),
 }
 
+#[derive(Clone, Debug)]
+pub enum GetBlockSelector {
+    Hash(BlockHash),
+    Height(u64),
+}
+
 impl DaemonClient {
+    /// Get a copy of this client whose calls fail with [`Cancelled`] as soon as `token` is cancelled, aborting the in-flight request.
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        let mut inner = self.inner.clone();
+        inner.cancellation = Some(token);
+        Self { inner }
+    }
+
+    /// Check that the endpoint is a daemon RPC server and not, for example, a wallet RPC server.
+    pub async fn verify(&self) -> anyhow::Result<()> {
+        self.inner
+            .verify_endpoint("get_block_count", "daemon")
+            .await
+    }
+
+    /// Fail with [`UnsupportedByDaemon`] if the daemon's RPC version is older than `required`, see `WalletClient::require_version`.
+    pub async fn require_version(
+        &self,
+        method: &'static str,
+        required: (u16, u16),
+    ) -> anyhow::Result<()> {
+        self.inner.require_version(method, required).await
+    }
+
     /// Look up how many blocks are in the longest chain known to the node.
     pub async fn get_block_count(&self) -> anyhow::Result<NonZeroU64> {
         #[derive(Deserialize)]
@@ -185,6 +683,54 @@ impl DaemonClient {
             .count)
     }
 
+    /// Get general information about the state of the node and the network.
+    pub async fn get_info(&self) -> anyhow::Result<DaemonInfo> {
+        Ok(self
+            .inner
+            .request::<MoneroResult<DaemonInfoR>>("get_info", RpcParams::None)
+            .await?
+            .into_inner()
+            .into())
+    }
+
+    /// List the daemon's P2P connections.
+    pub async fn get_connections(&self) -> anyhow::Result<Vec<ConnectionInfo>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            connections: Vec<ConnectionInfo>,
+        }
+
+        Ok(self
+            .inner
+            .request::<MoneroResult<Rsp>>("get_connections", RpcParams::None)
+            .await?
+            .into_inner()
+            .connections)
+    }
+
+    /// Get the daemon's synchronization state and the peers it syncs with.
+    pub async fn sync_info(&self) -> anyhow::Result<SyncInfo> {
+        Ok(self
+            .inner
+            .request::<MoneroResult<SyncInfoR>>("sync_info", RpcParams::None)
+            .await?
+            .into_inner()
+            .into())
+    }
+
+    /// Get `get_info`, `sync_info` and `get_connections` at once. The three requests are sent concurrently.
+    pub async fn node_status(&self) -> anyhow::Result<NodeStatus> {
+        let (info, sync, connections) =
+            tokio::try_join!(self.get_info(), self.sync_info(), self.get_connections())?;
+
+        Ok(NodeStatus {
+            info,
+            sync,
+            connections,
+        })
+    }
+
     /// Look up a block's hash by its height.
     pub async fn on_get_block_hash(&self, height: u64) -> anyhow::Result<BlockHash> {
         self.inner
@@ -202,6 +748,8 @@ impl DaemonClient {
         wallet_address: Address,
         reserve_size: u64,
     ) -> anyhow::Result<BlockTemplate> {
+        self.inner.check_address(&wallet_address)?;
+
         Ok(self
             .inner
             .request::<MoneroResult<BlockTemplate>>(
@@ -218,10 +766,15 @@ impl DaemonClient {
 
     /// Submit a mined block to the network.
     pub async fn submit_block(&self, block_blob_data: String) -> anyhow::Result<String> {
+        self.submit_blocks(vec![block_blob_data]).await
+    }
+
+    /// Submit several mined blocks to the network in one call.
+    pub async fn submit_blocks(&self, block_blobs_data: Vec<String>) -> anyhow::Result<String> {
         self.inner
             .request(
                 "submit_block",
-                RpcParams::array(once(block_blob_data.into())),
+                RpcParams::array(block_blobs_data.into_iter().map(Value::from)),
             )
             .await
     }
@@ -258,565 +811,2797 @@ impl DaemonClient {
             .into())
     }
 
+    /// Get a full block: its blob, header, parsed form and the hashes of its transactions.
+    pub async fn get_block(&self, selector: GetBlockSelector) -> anyhow::Result<GetBlockResponse> {
+        let params = match selector {
+            GetBlockSelector::Hash(hash) => {
+                once(("hash", serde_json::to_value(HashString(hash)).unwrap()))
+            }
+            GetBlockSelector::Height(height) => once(("height", height.into())),
+        };
+
+        let rsp = self
+            .inner
+            .request::<MoneroResult<GetBlockResponseR>>("get_block", RpcParams::map(params))
+            .await?
+            .into_inner();
+
+        GetBlockResponse::try_from(rsp)
+    }
+
+    /// Same as `DaemonClient::get_block`, also fetching the block's transactions in a single `get_transactions` call.
+    pub async fn get_block_with_txs(
+        &self,
+        selector: GetBlockSelector,
+    ) -> anyhow::Result<BlockWithTxs> {
+        let block = self.get_block(selector).await?;
+
+        let txs = if block.json.tx_hashes.is_empty() {
+            vec![]
+        } else {
+            let rsp = self
+                .get_transactions(
+                    block.json.tx_hashes.iter().map(|v| v.0).collect(),
+                    true,
+                    false,
+                )
+                .await?;
+            if !rsp.missed_tx.is_empty() {
+                anyhow::bail!(
+                    "Daemon is missing {} transactions of block {}",
+                    rsp.missed_tx.len(),
+                    block.block_header.height
+                );
+            }
+            rsp.txs
+        };
+
+        Ok(BlockWithTxs { block, txs })
+    }
+
     /// Similar to get_block_header_by_height above, but for a range of blocks. This method includes a starting block height and an ending block height as parameters to retrieve basic information about the range of blocks.
+    /// Ranges larger than the daemon's limit of 1000 headers per call are fetched in several calls. The returned flag is set if any of them was untrusted.
     pub async fn get_block_headers_range(
         &self,
         range: RangeInclusive<u64>,
     ) -> anyhow::Result<(Vec<BlockHeaderResponse>, bool)> {
+        const MAX_HEADERS_PER_CALL: u64 = 1000;
+
         #[derive(Deserialize)]
         struct Rsp {
             headers: Vec<BlockHeaderResponseR>,
             untrusted: bool,
         }
 
+        let mut all_headers = Vec::new();
+        let mut any_untrusted = false;
+
+        let mut start = *range.start();
+        while start <= *range.end() {
+            let end = (*range.end()).min(start.saturating_add(MAX_HEADERS_PER_CALL - 1));
+
+            let params = empty()
+                .chain(once(("start_height", start.into())))
+                .chain(once(("end_height", end.into())));
+
+            let Rsp { headers, untrusted } = self
+                .inner
+                .request::<MoneroResult<Rsp>>("get_block_headers_range", RpcParams::map(params))
+                .await?
+                .into_inner();
+
+            all_headers.extend(headers.into_iter().map(BlockHeaderResponse::from));
+            any_untrusted |= untrusted;
+
+            match end.checked_add(1) {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+
+        Ok((all_headers, any_untrusted))
+    }
+
+    /// Look up transactions by hash, in the pool or in the blockchain.
+    /// With `prune`, only the pruned part of each transaction blob is returned. With `decode_as_json`, transactions are also returned in parsed form.
+    /// Hashes the daemon does not know are listed in `missed_tx`.
+    pub async fn get_transactions(
+        &self,
+        txs_hashes: Vec<CryptoNoteHash>,
+        decode_as_json: bool,
+        prune: bool,
+    ) -> anyhow::Result<TransactionsResponse> {
         let params = empty()
-            .chain(once(("start_height", (*range.start()).into())))
-            .chain(once(("end_height", (*range.end()).into())));
+            .chain(once((
+                "txs_hashes",
+                txs_hashes
+                    .into_iter()
+                    .map(|v| HashString(v).to_string())
+                    .collect::<Vec<_>>()
+                    .into(),
+            )))
+            .chain(once(("decode_as_json", decode_as_json.into())))
+            .chain(once(("prune", prune.into())));
 
-        let Rsp { headers, untrusted } = self
+        let rsp = self
             .inner
-            .request::<MoneroResult<Rsp>>("get_block_headers_range", RpcParams::map(params))
+            .request_other::<MoneroResult<TransactionsResponseR>>(
+                "get_transactions",
+                RpcParams::map(params),
+            )
             .await?
             .into_inner();
 
-        Ok((headers.into_iter().map(From::from).collect(), untrusted))
-    }
-
-    /// Enable additional functions for regtest mode
-    pub fn regtest(self) -> RegtestDaemonClient {
-        RegtestDaemonClient(self)
+        TransactionsResponse::try_from(rsp)
     }
-}
 
-impl RegtestDaemonClient {
-    /// Generate blocks and give mining rewards to specified address.
-    pub async fn generate_blocks(
+    /// Submit a serialized transaction to the daemon. With `do_not_relay`, the daemon only adds it to its own pool.
+    /// A transaction the daemon rejects is reported in the result rather than as an error.
+    pub async fn send_raw_transaction(
         &self,
-        amount_of_blocks: u64,
-        wallet_address: Address,
-    ) -> anyhow::Result<u64> {
+        tx_as_hex: Vec<u8>,
+        do_not_relay: bool,
+    ) -> anyhow::Result<SendRawTransactionResult> {
+        let params = empty()
+            .chain(once((
+                "tx_as_hex",
+                HashString(tx_as_hex).to_string().into(),
+            )))
+            .chain(once(("do_not_relay", do_not_relay.into())));
+
+        let mut rsp = self
+            .inner
+            .request_other::<Value>("send_raw_transaction", RpcParams::map(params))
+            .await?;
+        strip_rpc_payment_fields(&mut rsp);
+
+        Ok(serde_json::from_value(rsp)?)
+    }
+
+    /// Check whether key images have been spent, in the blockchain or in the pool. Statuses are in the order of `key_images`.
+    pub async fn is_key_image_spent(
+        &self,
+        key_images: Vec<Vec<u8>>,
+    ) -> anyhow::Result<Vec<KeyImageSpentStatus>> {
         #[derive(Deserialize)]
         struct Rsp {
-            height: u64,
+            spent_status: Vec<u8>,
+        }
+
+        let params = once((
+            "key_images",
+            key_images
+                .into_iter()
+                .map(|v| HashString(v).to_string())
+                .collect::<Vec<_>>()
+                .into(),
+        ));
+
+        self.inner
+            .request_other::<MoneroResult<Rsp>>("is_key_image_spent", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .spent_status
+            .into_iter()
+            .map(KeyImageSpentStatus::try_from)
+            .collect()
+    }
+
+    /// Look up outputs by amount and global index, for instance to build rings. With `get_txid`, also return the transaction that created each output.
+    /// Outputs are returned in the order of `outputs`.
+    pub async fn get_outs(
+        &self,
+        outputs: Vec<GetOutsEntry>,
+        get_txid: bool,
+    ) -> anyhow::Result<Vec<OutKey>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            outs: Vec<OutKeyR>,
         }
 
         let params = empty()
-            .chain(once((
-                "amount_of_blocks",
-                serde_json::to_value(amount_of_blocks).unwrap(),
-            )))
-            .chain(once((
-                "wallet_address",
-                serde_json::to_value(wallet_address).unwrap(),
-            )));
+            .chain(once(("outputs", serde_json::to_value(outputs).unwrap())))
+            .chain(once(("get_txid", get_txid.into())));
 
-        Ok(self
+        let outs = self
             .inner
-            .request::<MoneroResult<Rsp>>("generateblocks", RpcParams::map(params))
+            .request_other::<MoneroResult<Rsp>>("get_outs", RpcParams::map(params))
             .await?
             .into_inner()
-            .height)
+            .outs;
+
+        outs.into_iter()
+            .map(|out| {
+                let out = OutKey::try_from(out)?;
+                // Without `get_txid`, the daemon may still send a zeroed txid.
+                Ok(OutKey {
+                    txid: out.txid.filter(|_| get_txid),
+                    ..out
+                })
+            })
+            .collect()
     }
-}
 
-impl Serialize for TransferPriority {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        serializer.serialize_u8(match self {
-            TransferPriority::Default => 0,
-            TransferPriority::Unimportant => 1,
-            TransferPriority::Elevated => 2,
-            TransferPriority::Priority => 3,
-        })
+    /// Get the current consensus version and the state of voting for the next one.
+    pub async fn hard_fork_info(&self) -> anyhow::Result<HardForkInfo> {
+        Ok(self
+            .inner
+            .request::<MoneroResult<HardForkInfo>>("hard_fork_info", RpcParams::None)
+            .await?
+            .into_inner())
     }
-}
 
-impl<'de> Deserialize<'de> for TransferPriority {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let v = u8::deserialize(deserializer)?;
-        Ok(match v {
-            0 => TransferPriority::Default,
-            1 => TransferPriority::Unimportant,
-            2 => TransferPriority::Elevated,
-            3 => TransferPriority::Priority,
-            other => {
-                return Err(serde::de::Error::custom(format!(
-                    "Invalid variant {}, expected 0-3",
-                    other
-                )))
+    /// Ban or unban peers.
+    pub async fn set_bans(&self, bans: Vec<Ban>) -> anyhow::Result<()> {
+        for ban in &bans {
+            if ban.host.is_some() == ban.ip.is_some() {
+                anyhow::bail!("Exactly one of host and ip must be set in {:?}", ban);
             }
-        })
-    }
-}
-
-#[derive(Clone, Debug)]
-pub struct WalletClient {
-    inner: CallerWrapper,
-}
+        }
 
-impl WalletClient {
-    /// Return the wallet's balance.
-    pub async fn get_balance(
-        &self,
-        account: u64,
-        addresses: Option<Vec<u64>>,
-    ) -> anyhow::Result<BalanceData> {
-        let params = empty()
-            .chain(once(account.into()))
-            .chain(addresses.map(Value::from));
+        let params = once(("bans", serde_json::to_value(bans).unwrap()));
 
         self.inner
-            .request("get_balance", RpcParams::array(params))
-            .await
-    }
-
-    /// Return the wallet's addresses for an account. Optionally filter for specific set of subaddresses.
-    pub async fn get_address(
-        &self,
-        account: u64,
-        addresses: Option<Vec<u64>>,
-    ) -> anyhow::Result<AddressData> {
-        let params = empty()
-            .chain(once(("account_index", account.into())))
-            .chain(addresses.map(|v| {
-                (
-                    "address_index",
-                    v.into_iter().map(Value::from).collect::<Vec<_>>().into(),
-                )
-            }));
+            .request::<MoneroResult<IgnoredAny>>("set_bans", RpcParams::map(params))
+            .await?;
 
-        self.inner
-            .request("get_address", RpcParams::map(params))
-            .await
+        Ok(())
     }
 
-    /// Get account and address indexes from a specific (sub)address.
-    pub async fn get_address_index(&self, address: Address) -> anyhow::Result<(u64, u64)> {
+    /// List banned peers.
+    pub async fn get_bans(&self) -> anyhow::Result<Vec<BannedHost>> {
         #[derive(Deserialize)]
         struct Rsp {
-            index: SubaddressIndex,
+            #[serde(default)]
+            bans: Vec<BannedHost>,
         }
 
-        let params = once(("address", address.to_string().into()));
-
-        let rsp = self
+        Ok(self
             .inner
-            .request::<Rsp>("get_address_index", RpcParams::map(params))
+            .request::<MoneroResult<Rsp>>("get_bans", RpcParams::None)
+            .await?
+            .into_inner()
+            .bans)
+    }
+
+    /// Remove transactions from the pool, or all of them if `txids` is `None`. Beware that the daemon also flushes the whole pool for an empty list.
+    pub async fn flush_txpool(&self, txids: Option<Vec<CryptoNoteHash>>) -> anyhow::Result<()> {
+        let params = empty().chain(txids.map(|v| {
+            (
+                "txids",
+                v.into_iter()
+                    .map(|v| HashString(v).to_string())
+                    .collect::<Vec<_>>()
+                    .into(),
+            )
+        }));
+
+        self.inner
+            .request::<MoneroResult<IgnoredAny>>("flush_txpool", RpcParams::map(params))
             .await?;
 
-        Ok((rsp.index.major, rsp.index.minor))
+        Ok(())
     }
 
-    /// Create a new address for an account. Optionally, label the new address.
-    pub async fn create_address(
-        &self,
-        account_index: u64,
-        label: Option<String>,
-    ) -> anyhow::Result<(Address, u64)> {
+    /// List the chains competing with the main chain that the daemon knows about.
+    pub async fn get_alternate_chains(&self) -> anyhow::Result<Vec<AlternateChain>> {
         #[derive(Deserialize)]
         struct Rsp {
-            address: Address,
-            address_index: u64,
+            #[serde(default)]
+            chains: Vec<AlternateChainR>,
         }
 
-        let params = empty()
-            .chain(once(("account_index", Value::Number(account_index.into()))))
-            .chain(label.map(|v| ("label", Value::String(v))));
+        Ok(self
+            .inner
+            .request::<MoneroResult<Rsp>>("get_alternate_chains", RpcParams::None)
+            .await?
+            .into_inner()
+            .chains
+            .into_iter()
+            .map(AlternateChain::from)
+            .collect())
+    }
+
+    /// Get the weight, fee and age of every transaction in the pool.
+    ///
+    /// The daemon's `get_txpool_backlog` method returns the entries as a binary blob embedded in JSON, which is not valid UTF-8 in general and cannot be parsed.
+    /// The backlog is computed from `DaemonClient::get_transaction_pool` instead, so `time_in_pool` is measured against the local clock.
+    pub async fn get_txpool_backlog(&self) -> anyhow::Result<Vec<TxBacklogEntry>> {
+        let now = chrono::Utc::now();
+
+        Ok(self
+            .get_transaction_pool()
+            .await?
+            .transactions
+            .into_iter()
+            .map(|tx| TxBacklogEntry {
+                weight: tx.weight,
+                fee: tx.fee,
+                time_in_pool: u64::try_from((now - tx.receive_time).num_seconds()).unwrap_or(0),
+            })
+            .collect())
+    }
 
+    /// Get the transactions in the pool, parsed, and the key images they spend.
+    pub async fn get_transaction_pool(&self) -> anyhow::Result<TxPool> {
         let rsp = self
             .inner
-            .request::<Rsp>("create_address", RpcParams::map(params))
-            .await?;
+            .request_other::<MoneroResult<TxPoolR>>("get_transaction_pool", RpcParams::None)
+            .await?
+            .into_inner();
 
-        Ok((rsp.address, rsp.address_index))
+        TxPool::try_from(rsp)
     }
 
-    /// Label an address.
-    pub async fn label_address(
+    /// Get the estimated fee per byte of transaction weight. `grace_blocks` is the number of blocks the estimate should stay valid for.
+    pub async fn get_fee_estimate(&self, grace_blocks: Option<u64>) -> anyhow::Result<FeeEstimate> {
+        let params = empty().chain(grace_blocks.map(|v| ("grace_blocks", v.into())));
+
+        Ok(self
+            .inner
+            .request::<MoneroResult<FeeEstimate>>("get_fee_estimate", RpcParams::map(params))
+            .await?
+            .into_inner())
+    }
+
+    /// Get the coins created and fees paid by the `count` blocks starting at `height`.
+    pub async fn get_coinbase_tx_sum(
         &self,
-        account_index: u64,
-        address_index: u64,
-        label: String,
-    ) -> anyhow::Result<()> {
+        height: u64,
+        count: u64,
+    ) -> anyhow::Result<CoinbaseTxSum> {
         let params = empty()
-            .chain(once((
-                "index",
-                json!(SubaddressIndex {
-                    major: account_index,
-                    minor: address_index,
-                }),
-            )))
-            .chain(once(("label", label.into())));
-
-        self.inner
-            .request::<IgnoredAny>("label_address", RpcParams::map(params))
-            .await?;
+            .chain(once(("height", height.into())))
+            .chain(once(("count", count.into())));
 
-        Ok(())
+        Ok(self
+            .inner
+            .request::<MoneroResult<CoinbaseTxSumR>>("get_coinbase_tx_sum", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .into())
     }
 
-    /// Get all accounts for a wallet. Optionally filter accounts by tag.
-    pub async fn get_accounts(&self, tag: Option<String>) -> anyhow::Result<GetAccountsData> {
-        let params = empty().chain(tag.map(|v| ("tag", v.into())));
+    /// Same as `DaemonClient::get_coinbase_tx_sum`, for the blocks in `range`.
+    pub async fn get_emission_in_range(
+        &self,
+        range: RangeInclusive<u64>,
+    ) -> anyhow::Result<CoinbaseTxSum> {
+        let count = if range.is_empty() {
+            0
+        } else {
+            (range.end() - range.start()).saturating_add(1)
+        };
 
-        self.inner
-            .request("get_accounts", RpcParams::map(params))
-            .await
+        self.get_coinbase_tx_sum(*range.start(), count).await
     }
 
-    /// Get a list of incoming payments using a given payment id.
-    pub async fn get_payments(&self, payment_id: PaymentId) -> anyhow::Result<Vec<Payment>> {
-        let params = empty().chain(once((
-            "payment_id",
-            HashString(payment_id).to_string().into(),
-        )));
+    /// Relay transactions that are already in the daemon's pool, identified by their hashes.
+    ///
+    /// This is different from [`WalletClient::relay_tx`], which takes the transaction metadata returned by a transfer made with `do_not_relay`.
+    ///
+    /// ```no_run
+    /// # async fn run(daemon: monero_rpc::DaemonClient, txid: monero::cryptonote::hash::Hash) -> anyhow::Result<()> {
+    /// daemon.relay_tx(vec![txid]).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn relay_tx(&self, txids: Vec<CryptoNoteHash>) -> anyhow::Result<()> {
+        let params = once((
+            "txids",
+            txids
+                .into_iter()
+                .map(|v| HashString(v).to_string())
+                .collect::<Vec<_>>()
+                .into(),
+        ));
 
         self.inner
-            .request("get_payments", RpcParams::map(params))
-            .await
+            .request::<MoneroResult<IgnoredAny>>("relay_tx", RpcParams::map(params))
+            .await?;
+
+        Ok(())
     }
 
-    /// Get a list of incoming payments using a given payment id, or a list of payments ids, from a given height.
-    /// This method is the preferred method over `WalletClient::get_payments` because it has the same functionality but is more extendable.
-    /// Either is fine for looking up transactions by a single payment ID.
-    pub async fn get_bulk_payments(
+    /// Get a histogram of the number of outputs per amount. An empty `amounts` requests all amounts.
+    /// Only amounts with between `min_count` and `max_count` outputs are returned, `max_count` of 0 meaning no upper bound.
+    /// `recent_instances` counts outputs newer than the `recent_cutoff` timestamp.
+    pub async fn get_output_histogram(
         &self,
-        payment_ids: Vec<PaymentId>,
-        min_block_height: u64,
-    ) -> anyhow::Result<Vec<Payment>> {
+        amounts: Vec<u64>,
+        min_count: u64,
+        max_count: u64,
+        unlocked: bool,
+        recent_cutoff: u64,
+    ) -> anyhow::Result<Vec<HistogramEntry>> {
         #[derive(Deserialize)]
         struct Rsp {
             #[serde(default)]
-            payments: Vec<Payment>,
+            histogram: Vec<HistogramEntry>,
         }
 
         let params = empty()
-            .chain(once((
-                "payment_ids",
-                payment_ids
-                    .into_iter()
-                    .map(|s| HashString(s).to_string())
-                    .collect::<Vec<_>>()
-                    .into(),
-            )))
-            .chain(once(("min_block_height", min_block_height.into())));
+            .chain(once(("amounts", amounts.into())))
+            .chain(once(("min_count", min_count.into())))
+            .chain(once(("max_count", max_count.into())))
+            .chain(once(("unlocked", unlocked.into())))
+            .chain(once(("recent_cutoff", recent_cutoff.into())));
 
-        self.inner
-            .request::<Rsp>("get_bulk_payments", RpcParams::map(params))
-            .await
-            .map(|rsp| rsp.payments)
+        Ok(self
+            .inner
+            .request::<MoneroResult<Rsp>>("get_output_histogram", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .histogram)
     }
 
-    /// Return the view private key.
-    pub async fn query_view_key(&self) -> anyhow::Result<monero::PrivateKey> {
+    /// Get the total number of RingCT outputs on the chain, i.e. the `total_instances` of amount 0 in the output histogram.
+    pub async fn get_rct_output_count(&self) -> anyhow::Result<u64> {
+        Ok(self
+            .get_output_histogram(vec![0], 0, 0, false, 0)
+            .await?
+            .into_iter()
+            .find(|entry| entry.amount == 0)
+            .map(|entry| entry.total_instances)
+            .unwrap_or(0))
+    }
+
+    /// Get the number of outputs of each of `amounts` per block from `from_height` to `to_height` (0 for the top of the chain).
+    /// With `cumulative`, the distribution holds running totals, as needed for decoy selection.
+    ///
+    /// Only the JSON form is supported: the binary form is embedded in JSON as raw bytes that cannot be parsed, so `binary` must be `false`.
+    pub async fn get_output_distribution(
+        &self,
+        amounts: Vec<u64>,
+        from_height: u64,
+        to_height: u64,
+        cumulative: bool,
+        binary: bool,
+    ) -> anyhow::Result<Vec<OutputDistribution>> {
         #[derive(Deserialize)]
         struct Rsp {
-            key: HashString<Vec<u8>>,
+            #[serde(default)]
+            distributions: Vec<OutputDistribution>,
         }
 
-        let params = empty().chain(once(("key_type", "view_key".into())));
+        if binary {
+            anyhow::bail!(
+                "Binary output distributions are not supported, request the JSON form instead"
+            );
+        }
 
-        let rsp = self
-            .inner
+        let params = empty()
+            .chain(once(("amounts", amounts.into())))
+            .chain(once(("from_height", from_height.into())))
+            .chain(once(("to_height", to_height.into())))
+            .chain(once(("cumulative", cumulative.into())))
+            .chain(once(("binary", binary.into())));
+
+        Ok(self
+            .inner
+            .request::<MoneroResult<Rsp>>("get_output_distribution", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .distributions)
+    }
+
+    /// Get the daemon's bandwidth limits in kB/s, as `(limit_down, limit_up)`.
+    pub async fn get_limit(&self) -> anyhow::Result<(u64, u64)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            limit_down: u64,
+            limit_up: u64,
+        }
+
+        let rsp = self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("get_limit", RpcParams::None)
+            .await?
+            .into_inner();
+
+        Ok((rsp.limit_down, rsp.limit_up))
+    }
+
+    /// Set the daemon's bandwidth limits in kB/s. For each limit, -1 resets it to the default and 0 leaves it unchanged.
+    /// Returns the new limits, as `(limit_down, limit_up)`.
+    pub async fn set_limit(&self, limit_down: i64, limit_up: i64) -> anyhow::Result<(u64, u64)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            limit_down: u64,
+            limit_up: u64,
+        }
+
+        let params = empty()
+            .chain(once(("limit_down", limit_down.into())))
+            .chain(once(("limit_up", limit_up.into())));
+
+        let rsp = self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("set_limit", RpcParams::map(params))
+            .await?
+            .into_inner();
+
+        Ok((rsp.limit_down, rsp.limit_up))
+    }
+
+    /// Set the maximum number of outgoing peers, `u32::MAX` meaning unlimited. Returns the new limit.
+    pub async fn set_out_peers(&self, n: u32) -> anyhow::Result<u32> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            out_peers: u32,
+        }
+
+        let params = once(("out_peers", n.into()));
+
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("out_peers", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .out_peers)
+    }
+
+    /// Set the maximum number of incoming peers, `u32::MAX` meaning unlimited. Returns the new limit.
+    pub async fn set_in_peers(&self, n: u32) -> anyhow::Result<u32> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            in_peers: u32,
+        }
+
+        let params = once(("in_peers", n.into()));
+
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("in_peers", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .in_peers)
+    }
+
+    /// Start mining on the daemon, rewarding `miner_address`.
+    pub async fn start_mining(
+        &self,
+        do_background_mining: bool,
+        ignore_battery: bool,
+        miner_address: Address,
+        threads_count: u64,
+    ) -> anyhow::Result<()> {
+        self.inner.check_address(&miner_address)?;
+
+        let params = empty()
+            .chain(once(("do_background_mining", do_background_mining.into())))
+            .chain(once(("ignore_battery", ignore_battery.into())))
+            .chain(once(("miner_address", miner_address.to_string().into())))
+            .chain(once(("threads_count", threads_count.into())));
+
+        self.inner
+            .request_other::<MoneroResult<IgnoredAny>>("start_mining", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Stop mining on the daemon.
+    pub async fn stop_mining(&self) -> anyhow::Result<()> {
+        self.inner
+            .request_other::<MoneroResult<IgnoredAny>>("stop_mining", RpcParams::None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the state of the daemon's miner.
+    pub async fn mining_status(&self) -> anyhow::Result<MiningStatus> {
+        let rsp = self
+            .inner
+            .request_other::<MoneroResult<MiningStatusR>>("mining_status", RpcParams::None)
+            .await?
+            .into_inner();
+
+        MiningStatus::try_from(rsp)
+    }
+
+    /// List the peers the daemon knows about, connected or not. See `DaemonClient::get_connections` for live connections.
+    pub async fn get_peer_list(&self) -> anyhow::Result<PeerLists> {
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<PeerLists>>("get_peer_list", RpcParams::None)
+            .await?
+            .into_inner())
+    }
+
+    /// Set the daemon's log level, from 0 (least verbose) to 4.
+    pub async fn set_log_level(&self, level: i8) -> anyhow::Result<()> {
+        let params = once(("level", level.into()));
+
+        self.inner
+            .request_other::<MoneroResult<IgnoredAny>>("set_log_level", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set the daemon's log categories, e.g. `"*:WARNING,net.p2p:DEBUG"`. Returns the categories now in effect.
+    pub async fn set_log_categories(&self, categories: String) -> anyhow::Result<String> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            categories: String,
+        }
+
+        let params = once(("categories", categories.into()));
+
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("set_log_categories", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .categories)
+    }
+
+    /// Get the daemon's network traffic since it started.
+    pub async fn get_net_stats(&self) -> anyhow::Result<NetStats> {
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<NetStats>>("get_net_stats", RpcParams::None)
+            .await?
+            .into_inner())
+    }
+
+    /// Get the daemon's RPC version. The version number is split like `WalletClient::get_version`'s.
+    pub async fn get_version(&self) -> anyhow::Result<DaemonVersion> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            version: u32,
+            release: bool,
+        }
+
+        let rsp = self
+            .inner
+            .request::<MoneroResult<Rsp>>("get_version", RpcParams::None)
+            .await?
+            .into_inner();
+
+        Ok(DaemonVersion {
+            major: (rsp.version >> 16) as u16,
+            minor: rsp.version as u16,
+            release: rsp.release,
+        })
+    }
+
+    /// Get aggregate statistics of the transaction pool.
+    pub async fn get_tx_pool_stats(&self) -> anyhow::Result<TxPoolStats> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            pool_stats: TxPoolStats,
+        }
+
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("get_transaction_pool_stats", RpcParams::None)
+            .await?
+            .into_inner()
+            .pool_stats)
+    }
+
+    /// Enable additional functions for regtest mode
+    pub fn regtest(self) -> RegtestDaemonClient {
+        RegtestDaemonClient(self)
+    }
+
+    /// Memoize results of idempotent calls for `ttl`. Calls without a cache are forwarded as-is.
+    pub fn cached(self, ttl: Duration) -> CachedDaemonClient {
+        CachedDaemonClient {
+            inner: self,
+            ttl,
+            cache: Default::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TtlCell<T>(Mutex<Option<(Instant, T)>>);
+
+impl<T> Default for TtlCell<T> {
+    fn default() -> Self {
+        Self(Mutex::new(None))
+    }
+}
+
+impl<T> TtlCell<T>
+where
+    T: Clone,
+{
+    async fn get_or_fetch<F>(&self, ttl: Duration, fetch: F) -> anyhow::Result<T>
+    where
+        F: Future<Output = anyhow::Result<T>>,
+    {
+        let cached = self
+            .0
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < ttl)
+            .map(|(_, v)| v.clone());
+
+        if let Some(v) = cached {
+            return Ok(v);
+        }
+
+        let v = fetch.await?;
+        *self.0.lock().unwrap() = Some((Instant::now(), v.clone()));
+
+        Ok(v)
+    }
+}
+
+/// Like [`TtlCell`], but caching one value per key, for methods taking arguments.
+#[derive(Debug)]
+struct TtlMap<K, V>(Mutex<HashMap<K, (Instant, V)>>);
+
+impl<K, V> Default for TtlMap<K, V> {
+    fn default() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+impl<K, V> TtlMap<K, V>
+where
+    K: Eq + std::hash::Hash,
+    V: Clone,
+{
+    async fn get_or_fetch<F>(&self, key: K, ttl: Duration, fetch: F) -> anyhow::Result<V>
+    where
+        F: Future<Output = anyhow::Result<V>>,
+    {
+        let cached = self
+            .0
+            .lock()
+            .unwrap()
+            .get(&key)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < ttl)
+            .map(|(_, v)| v.clone());
+
+        if let Some(v) = cached {
+            return Ok(v);
+        }
+
+        let v = fetch.await?;
+        let mut entries = self.0.lock().unwrap();
+        entries.retain(|_, (fetched_at, _)| fetched_at.elapsed() < ttl);
+        entries.insert(key, (Instant::now(), v.clone()));
+
+        Ok(v)
+    }
+}
+
+#[derive(Debug, Default)]
+struct DaemonCache {
+    block_count: TtlCell<NonZeroU64>,
+    info: TtlCell<DaemonInfo>,
+    version: TtlCell<DaemonVersion>,
+    fee_estimate: TtlMap<Option<u64>, FeeEstimate>,
+}
+
+/// Daemon client that caches results of idempotent calls for a fixed duration, each method separately.
+/// Use it for read-heavy workloads where slightly stale data is acceptable. Clones share the cache.
+#[derive(Clone, Debug)]
+pub struct CachedDaemonClient {
+    inner: DaemonClient,
+    ttl: Duration,
+    cache: Arc<DaemonCache>,
+}
+
+impl Deref for CachedDaemonClient {
+    type Target = DaemonClient;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl CachedDaemonClient {
+    /// Cached version of [`DaemonClient::get_block_count`].
+    pub async fn get_block_count(&self) -> anyhow::Result<NonZeroU64> {
+        self.cache
+            .block_count
+            .get_or_fetch(self.ttl, self.inner.get_block_count())
+            .await
+    }
+
+    /// Cached version of [`DaemonClient::get_info`].
+    pub async fn get_info(&self) -> anyhow::Result<DaemonInfo> {
+        self.cache
+            .info
+            .get_or_fetch(self.ttl, self.inner.get_info())
+            .await
+    }
+
+    /// Cached version of [`DaemonClient::get_version`].
+    pub async fn get_version(&self) -> anyhow::Result<DaemonVersion> {
+        self.cache
+            .version
+            .get_or_fetch(self.ttl, self.inner.get_version())
+            .await
+    }
+
+    /// Cached version of [`DaemonClient::get_fee_estimate`], cached separately for each `grace_blocks`.
+    pub async fn get_fee_estimate(&self, grace_blocks: Option<u64>) -> anyhow::Result<FeeEstimate> {
+        self.cache
+            .fee_estimate
+            .get_or_fetch(
+                grace_blocks,
+                self.ttl,
+                self.inner.get_fee_estimate(grace_blocks),
+            )
+            .await
+    }
+}
+
+/// Whether `e` comes from a connection that failed or was dropped, typically an idle connection closed by a load balancer.
+fn is_connection_error(e: &anyhow::Error) -> bool {
+    let io_error = e.chain().any(|cause| {
+        cause.downcast_ref::<std::io::Error>().is_some_and(|e| {
+            matches!(
+                e.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+                    | std::io::ErrorKind::UnexpectedEof
+            )
+        })
+    });
+
+    io_error
+        || e.downcast_ref::<reqwest::Error>()
+            .is_some_and(|e| e.is_connect() || (e.is_request() && !e.is_timeout()))
+}
+
+/// Daemon client that retries read methods once on a new connection when the first attempt fails because of the connection,
+/// e.g. "connection reset by peer" after a load balancer dropped an idle connection.
+///
+/// Only the methods implemented here retry. Other methods, including all writes, are reached through `Deref` and never retried.
+#[derive(Clone, Debug)]
+pub struct ResilientDaemonClient {
+    inner: DaemonClient,
+    /// Client without idle connections, used for retries.
+    fresh: DaemonClient,
+}
+
+impl Deref for ResilientDaemonClient {
+    type Target = DaemonClient;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl ResilientDaemonClient {
+    pub fn new(addr: impl Into<String>, options: RpcClientOptions) -> Self {
+        let addr = addr.into();
+        Self {
+            inner: RpcClient::build(addr.clone(), options.clone(), true).daemon(),
+            fresh: RpcClient::build(addr, options, false).daemon(),
+        }
+    }
+
+    async fn read<'a, F, Fut, T>(&'a self, f: F) -> anyhow::Result<T>
+    where
+        F: Fn(&'a DaemonClient) -> Fut,
+        Fut: Future<Output = anyhow::Result<T>>,
+    {
+        match f(&self.inner).await {
+            Err(e) if is_connection_error(&e) => {
+                debug!("Retrying on a new connection after: {}", e);
+                f(&self.fresh).await
+            }
+            v => v,
+        }
+    }
+
+    /// Retrying version of [`DaemonClient::get_block_count`].
+    pub async fn get_block_count(&self) -> anyhow::Result<NonZeroU64> {
+        self.read(|c| c.get_block_count()).await
+    }
+
+    /// Retrying version of [`DaemonClient::get_info`].
+    pub async fn get_info(&self) -> anyhow::Result<DaemonInfo> {
+        self.read(|c| c.get_info()).await
+    }
+
+    /// Retrying version of [`DaemonClient::sync_info`].
+    pub async fn sync_info(&self) -> anyhow::Result<SyncInfo> {
+        self.read(|c| c.sync_info()).await
+    }
+
+    /// Retrying version of [`DaemonClient::on_get_block_hash`].
+    pub async fn on_get_block_hash(&self, height: u64) -> anyhow::Result<BlockHash> {
+        self.read(|c| c.on_get_block_hash(height)).await
+    }
+
+    /// Retrying version of [`DaemonClient::get_block_header`].
+    pub async fn get_block_header(
+        &self,
+        selector: GetBlockHeaderSelector,
+    ) -> anyhow::Result<BlockHeaderResponse> {
+        self.read(|c| c.get_block_header(selector.clone())).await
+    }
+
+    /// Retrying version of [`DaemonClient::get_block`].
+    pub async fn get_block(&self, selector: GetBlockSelector) -> anyhow::Result<GetBlockResponse> {
+        self.read(|c| c.get_block(selector.clone())).await
+    }
+
+    /// Retrying version of [`DaemonClient::get_transactions`].
+    pub async fn get_transactions(
+        &self,
+        txs_hashes: Vec<CryptoNoteHash>,
+        decode_as_json: bool,
+        prune: bool,
+    ) -> anyhow::Result<TransactionsResponse> {
+        self.read(|c| c.get_transactions(txs_hashes.clone(), decode_as_json, prune))
+            .await
+    }
+
+    /// Retrying version of [`DaemonClient::get_fee_estimate`].
+    pub async fn get_fee_estimate(&self, grace_blocks: Option<u64>) -> anyhow::Result<FeeEstimate> {
+        self.read(|c| c.get_fee_estimate(grace_blocks)).await
+    }
+}
+
+impl RegtestDaemonClient {
+    /// Generate blocks and give mining rewards to specified address.
+    /// Set `starting_nonce` to make the generated blocks identical across runs.
+    pub async fn generate_blocks(
+        &self,
+        amount_of_blocks: u64,
+        wallet_address: Address,
+        starting_nonce: Option<u32>,
+    ) -> anyhow::Result<u64> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            height: u64,
+        }
+
+        self.inner.check_address(&wallet_address)?;
+
+        let params = empty()
+            .chain(once((
+                "amount_of_blocks",
+                serde_json::to_value(amount_of_blocks).unwrap(),
+            )))
+            .chain(once((
+                "wallet_address",
+                serde_json::to_value(wallet_address).unwrap(),
+            )))
+            .chain(starting_nonce.map(|v| ("starting_nonce", v.into())));
+
+        Ok(self
+            .inner
+            .request::<MoneroResult<Rsp>>("generateblocks", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .height)
+    }
+
+    /// Remove the `nblocks` top blocks from the chain. Returns the new height.
+    pub async fn pop_blocks(&self, nblocks: u64) -> anyhow::Result<u64> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            height: u64,
+        }
+
+        let params = once(("nblocks", nblocks.into()));
+
+        Ok(self
+            .inner
+            .request_other::<MoneroResult<Rsp>>("pop_blocks", RpcParams::map(params))
+            .await?
+            .into_inner()
+            .height)
+    }
+
+    /// Mine [`MINED_MONEY_UNLOCK_WINDOW`] blocks to `address`, so that the coinbase of the first one becomes spendable. Returns the new height.
+    pub async fn generate_blocks_to_unlock(&self, address: Address) -> anyhow::Result<u64> {
+        self.generate_blocks(MINED_MONEY_UNLOCK_WINDOW, address, None)
+            .await
+    }
+}
+
+impl Serialize for TransferPriority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u8(match self {
+            TransferPriority::Default => 0,
+            TransferPriority::Unimportant => 1,
+            TransferPriority::Elevated => 2,
+            TransferPriority::Priority => 3,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for TransferPriority {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let v = u8::deserialize(deserializer)?;
+        Ok(match v {
+            0 => TransferPriority::Default,
+            1 => TransferPriority::Unimportant,
+            2 => TransferPriority::Elevated,
+            3 => TransferPriority::Priority,
+            other => {
+                return Err(serde::de::Error::custom(format!(
+                    "Invalid variant {}, expected 0-3",
+                    other
+                )))
+            }
+        })
+    }
+}
+
+/// Cold-signing workflow on the offline side: inspect an unsigned transaction set exported by a view-only wallet,
+/// then sign it with a spend-capable wallet that never touches the network.
+#[derive(Clone, Debug)]
+pub struct ColdSigning {
+    offline_wallet: WalletClient,
+}
+
+impl ColdSigning {
+    pub fn new(offline_wallet: WalletClient) -> Self {
+        Self { offline_wallet }
+    }
+
+    /// Describe `unsigned_txset` and sign it if `approve` accepts the descriptions.
+    /// Returns the descriptions along with the signed set, whose `signed_txset` is then passed to `WalletClient::submit_transfer` on the view-only wallet.
+    pub async fn sign<F>(
+        &self,
+        unsigned_txset: Vec<u8>,
+        approve: F,
+    ) -> anyhow::Result<(Vec<TransferDescription>, SignedTransferOutput)>
+    where
+        F: FnOnce(&[TransferDescription]) -> bool + Send,
+    {
+        let descriptions = self
+            .offline_wallet
+            .describe_transfer(unsigned_txset.clone())
+            .await?;
+
+        if descriptions.is_empty() {
+            anyhow::bail!("Unsigned transaction set contains no transactions");
+        }
+
+        if !approve(&descriptions) {
+            anyhow::bail!("Unsigned transaction set was rejected");
+        }
+
+        let signed = self.offline_wallet.sign_transfer(unsigned_txset).await?;
+
+        Ok((descriptions, signed))
+    }
+}
+
+/// Wallet RPC versions, as `(major, minor)`, that introduced the methods and parameters below.
+/// Methods using them call `require_version` first, so that older servers fail with [`UnsupportedByDaemon`].
+const GENERATE_FROM_KEYS_VERSION: (u16, u16) = (1, 9);
+const SET_DAEMON_VERSION: (u16, u16) = (1, 11);
+const RESTORE_DETERMINISTIC_WALLET_VERSION: (u16, u16) = (1, 11);
+const CREATE_ADDRESS_COUNT_VERSION: (u16, u16) = (1, 14);
+const ESTIMATE_TX_SIZE_AND_WEIGHT_VERSION: (u16, u16) = (1, 18);
+
+/// Longest range of subaddress indices `get_address_range` lists in its request.
+const MAX_LISTED_ADDRESS_RANGE: u64 = 1000;
+
+#[derive(Clone, Debug)]
+pub struct WalletClient {
+    inner: CallerWrapper,
+}
+
+impl WalletClient {
+    /// Get a copy of this client whose calls fail with [`Cancelled`] as soon as `token` is cancelled, aborting the in-flight request.
+    /// Useful for long operations such as `get_reserve_proof` on large wallets.
+    pub fn with_cancellation(&self, token: CancellationToken) -> Self {
+        let mut inner = self.inner.clone();
+        inner.cancellation = Some(token);
+        Self { inner }
+    }
+
+    /// Check that the endpoint is a wallet RPC server and not, for example, a daemon RPC server.
+    pub async fn verify(&self) -> anyhow::Result<()> {
+        self.inner.verify_endpoint("get_height", "wallet").await
+    }
+
+    /// Fail with [`UnsupportedByDaemon`] if the wallet RPC server is older than `required` (`(major, minor)`, see `get_version`).
+    /// Methods that only exist on newer servers call this before sending their request, so that an old server gives a clear error instead of "method not found".
+    /// The version is only fetched once per client.
+    pub async fn require_version(
+        &self,
+        method: &'static str,
+        required: (u16, u16),
+    ) -> anyhow::Result<()> {
+        self.inner.require_version(method, required).await
+    }
+
+    /// Return the wallet's balance.
+    pub async fn get_balance(
+        &self,
+        account: u64,
+        addresses: Option<Vec<u64>>,
+    ) -> anyhow::Result<BalanceData> {
+        let params = empty()
+            .chain(once(account.into()))
+            .chain(addresses.map(Value::from));
+
+        self.inner
+            .request("get_balance", RpcParams::array(params))
+            .await
+    }
+
+    /// Return the balance of an account that can be spent right now, i.e. its unlocked balance.
+    /// The total balance also counts outputs that are still locked, for instance received less than 10 blocks ago.
+    pub async fn spendable_balance(&self, account_index: u64) -> anyhow::Result<u64> {
+        Ok(self
+            .get_balance(account_index, None)
+            .await?
+            .unlocked_balance)
+    }
+
+    /// Check whether an account can spend `amount` right now. The fee is not accounted for.
+    pub async fn can_spend(&self, account_index: u64, amount: u64) -> anyhow::Result<bool> {
+        Ok(self.spendable_balance(account_index).await? >= amount)
+    }
+
+    /// Return the wallet's addresses for an account. Optionally filter for specific set of subaddresses.
+    pub async fn get_address(
+        &self,
+        account: u64,
+        addresses: Option<Vec<u64>>,
+    ) -> anyhow::Result<AddressData> {
+        let params = empty()
+            .chain(once(("account_index", account.into())))
+            .chain(addresses.map(|v| {
+                (
+                    "address_index",
+                    v.into_iter().map(Value::from).collect::<Vec<_>>().into(),
+                )
+            }));
+
+        self.inner
+            .request("get_address", RpcParams::map(params))
+            .await
+    }
+
+    /// Return the wallet's addresses for an account whose indices fall within `indices`. An empty range returns no addresses without a request.
+    /// Ranges without an end or spanning more than 1000 indices fetch all addresses of the account and filter them;
+    /// shorter ones list their indices, which fails if some of them do not exist yet.
+    pub async fn get_address_range<T>(
+        &self,
+        account_index: u64,
+        indices: T,
+    ) -> anyhow::Result<Vec<SubaddressData>>
+    where
+        T: RangeBounds<u64> + Send,
+    {
+        let start = match indices.start_bound() {
+            Bound::Included(b) => Some(*b),
+            Bound::Excluded(b) => b.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match indices.end_bound() {
+            Bound::Included(b) => Some(Some(*b)),
+            Bound::Excluded(b) => Some(b.checked_sub(1)),
+            Bound::Unbounded => None,
+        };
+
+        let (start, end) = match (start, end) {
+            (None, _) | (_, Some(None)) => return Ok(vec![]),
+            (Some(start), Some(Some(end))) if end < start => return Ok(vec![]),
+            (Some(start), end) => (start, end.flatten()),
+        };
+
+        match end {
+            Some(end) if end - start < MAX_LISTED_ADDRESS_RANGE => Ok(self
+                .get_address(account_index, Some((start..=end).collect()))
+                .await?
+                .addresses),
+            _ => {
+                let mut addresses = self.get_address(account_index, None).await?.addresses;
+                let range = start..=end.unwrap_or(u64::MAX);
+                addresses.retain(|v| range.contains(&v.address_index));
+                Ok(addresses)
+            }
+        }
+    }
+
+    /// Get account and address indexes from a specific (sub)address.
+    pub async fn get_address_index(&self, address: Address) -> anyhow::Result<(u64, u64)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            index: SubaddressIndex,
+        }
+
+        self.inner.check_address(&address)?;
+
+        let params = once(("address", address.to_string().into()));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("get_address_index", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.index.major, rsp.index.minor))
+    }
+
+    /// Create a new address for an account. Optionally, label the new address.
+    pub async fn create_address(
+        &self,
+        account_index: u64,
+        label: Option<String>,
+    ) -> anyhow::Result<(Address, u64)> {
+        self.create_addresses(account_index, 1, label)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("Wallet returned no address"))
+    }
+
+    /// Create `count` new addresses for an account, all with the same optional label.
+    /// Fails with [`UnsupportedByDaemon`] for a `count` above 1 on wallets that only create one address at a time.
+    pub async fn create_addresses(
+        &self,
+        account_index: u64,
+        count: u64,
+        label: Option<String>,
+    ) -> anyhow::Result<Vec<(Address, u64)>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            address: Address,
+            address_index: u64,
+            #[serde(default)]
+            addresses: Vec<Address>,
+            #[serde(default)]
+            address_indices: Vec<u64>,
+        }
+
+        if count > 1 {
+            self.inner
+                .require_version("create_address", CREATE_ADDRESS_COUNT_VERSION)
+                .await?;
+        }
+
+        let params = empty()
+            .chain(once(("account_index", Value::Number(account_index.into()))))
+            .chain(once(("count", count.into())))
+            .chain(label.map(|v| ("label", Value::String(v))));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("create_address", RpcParams::map(params))
+            .await?;
+
+        // Older wallets ignore `count` and only return the single address fields.
+        if rsp.addresses.is_empty() {
+            return Ok(vec![(rsp.address, rsp.address_index)]);
+        }
+
+        Ok(rsp.addresses.into_iter().zip(rsp.address_indices).collect())
+    }
+
+    /// Label an address.
+    pub async fn label_address(
+        &self,
+        account_index: u64,
+        address_index: u64,
+        label: String,
+    ) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(once((
+                "index",
+                json!(SubaddressIndex {
+                    major: account_index,
+                    minor: address_index,
+                }),
+            )))
+            .chain(once(("label", label.into())));
+
+        self.inner
+            .request::<IgnoredAny>("label_address", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get all accounts for a wallet. Optionally filter accounts by tag.
+    pub async fn get_accounts(&self, tag: Option<String>) -> anyhow::Result<GetAccountsData> {
+        self.get_accounts_with_options(tag, None, None).await
+    }
+
+    /// Same as `WalletClient::get_accounts`, with newer wallet options:
+    /// `regexp` matches `tag` as a regular expression, and `strict_balances` only counts fully unlocked outputs in balances.
+    /// Options left as `None` are not sent, so older wallets keep working.
+    pub async fn get_accounts_with_options(
+        &self,
+        tag: Option<String>,
+        regexp: Option<bool>,
+        strict_balances: Option<bool>,
+    ) -> anyhow::Result<GetAccountsData> {
+        let params = empty()
+            .chain(tag.map(|v| ("tag", v.into())))
+            .chain(regexp.map(|v| ("regexp", v.into())))
+            .chain(strict_balances.map(|v| ("strict_balances", v.into())));
+
+        self.inner
+            .request("get_accounts", RpcParams::map(params))
+            .await
+    }
+
+    /// Create a new account, returning its index and primary address.
+    pub async fn create_account(&self, label: Option<String>) -> anyhow::Result<(u64, Address)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            account_index: u64,
+            address: Address,
+        }
+
+        let params = empty().chain(label.map(|v| ("label", v.into())));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("create_account", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.account_index, rsp.address))
+    }
+
+    /// Get all account tags with their description and tagged accounts.
+    pub async fn get_account_tags(&self) -> anyhow::Result<Vec<AccountTag>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            account_tags: Vec<AccountTag>,
+        }
+
+        self.inner
+            .request::<Rsp>("get_account_tags", RpcParams::None)
+            .await
+            .map(|v| v.account_tags)
+    }
+
+    /// Tag `accounts` with `tag`, replacing any tag they had. The tag can then be passed to `WalletClient::get_accounts`.
+    pub async fn tag_accounts(&self, tag: String, accounts: Vec<u64>) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(once(("tag", tag.into())))
+            .chain(once(("accounts", accounts.into())));
+
+        self.inner
+            .request::<IgnoredAny>("tag_accounts", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Remove the tag of `accounts`.
+    pub async fn untag_accounts(&self, accounts: Vec<u64>) -> anyhow::Result<()> {
+        let params = once(("accounts", accounts.into()));
+
+        self.inner
+            .request::<IgnoredAny>("untag_accounts", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Set the description of an existing account tag.
+    pub async fn set_account_tag_description(
+        &self,
+        tag: String,
+        description: String,
+    ) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(once(("tag", tag.into())))
+            .chain(once(("description", description.into())));
+
+        self.inner
+            .request::<IgnoredAny>("set_account_tag_description", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Make an integrated address from the wallet's primary address, or `standard_address` if given, and a payment ID. Returns the address and the embedded payment ID.
+    /// If `payment_id` is `None`, the wallet generates a random one.
+    ///
+    /// Integrated addresses embed short 8-byte payment IDs, which is the only size `PaymentId` can hold.
+    /// Long 32-byte payment IDs are deprecated and only appear in old payments; they cannot be embedded in an address.
+    pub async fn make_integrated_address(
+        &self,
+        standard_address: Option<Address>,
+        payment_id: Option<PaymentId>,
+    ) -> anyhow::Result<(Address, PaymentId)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            integrated_address: Address,
+            payment_id: HashString<PaymentId>,
+        }
+
+        if let Some(address) = &standard_address {
+            self.inner.check_address(address)?;
+            if address.addr_type != AddressType::Standard {
+                anyhow::bail!(
+                    "Integrated addresses can only be made from standard addresses, {} is {:?}",
+                    address,
+                    address.addr_type
+                );
+            }
+        }
+
+        let params = empty()
+            .chain(standard_address.map(|v| ("standard_address", v.to_string().into())))
+            .chain(payment_id.map(|v| ("payment_id", HashString(v).to_string().into())));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("make_integrated_address", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.integrated_address, rsp.payment_id.0))
+    }
+
+    /// Get a list of incoming payments using a given payment id.
+    pub async fn get_payments(&self, payment_id: PaymentId) -> anyhow::Result<Vec<Payment>> {
+        let params = empty().chain(once((
+            "payment_id",
+            HashString(payment_id).to_string().into(),
+        )));
+
+        self.inner
+            .request("get_payments", RpcParams::map(params))
+            .await
+    }
+
+    /// Get a list of incoming payments using a given payment id, or a list of payments ids, from a given height.
+    /// This method is the preferred method over `WalletClient::get_payments` because it has the same functionality but is more extendable.
+    /// Either is fine for looking up transactions by a single payment ID.
+    pub async fn get_bulk_payments(
+        &self,
+        payment_ids: Vec<PaymentId>,
+        min_block_height: u64,
+    ) -> anyhow::Result<Vec<Payment>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            payments: Vec<Payment>,
+        }
+
+        let params = empty()
+            .chain(once((
+                "payment_ids",
+                payment_ids
+                    .into_iter()
+                    .map(|s| HashString(s).to_string())
+                    .collect::<Vec<_>>()
+                    .into(),
+            )))
+            .chain(once(("min_block_height", min_block_height.into())));
+
+        self.inner
+            .request::<Rsp>("get_bulk_payments", RpcParams::map(params))
+            .await
+            .map(|rsp| rsp.payments)
+    }
+
+    /// Return the view private key.
+    pub async fn query_view_key(&self) -> anyhow::Result<monero::PrivateKey> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            key: HashString<Vec<u8>>,
+        }
+
+        let params = empty().chain(once(("key_type", "view_key".into())));
+
+        let rsp = self
+            .inner
             .request::<Rsp>("query_key", RpcParams::map(params))
             .await?;
 
-        Ok(monero::PrivateKey::from_slice(&rsp.key.0)?)
+        Ok(monero::PrivateKey::from_slice(&rsp.key.0)?)
+    }
+
+    /// Returns the wallet's current block height.
+    pub async fn get_height(&self) -> anyhow::Result<NonZeroU64> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            height: NonZeroU64,
+        }
+
+        Ok(self
+            .inner
+            .request::<Rsp>("get_height", RpcParams::None)
+            .await?
+            .height)
+    }
+
+    /// Refresh the wallet, optionally scanning from `start_height`. Returns the number of blocks fetched and whether money was received.
+    pub async fn refresh(&self, start_height: Option<u64>) -> anyhow::Result<(u64, bool)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            blocks_fetched: u64,
+            received_money: bool,
+        }
+
+        let params = empty().chain(start_height.map(|v| ("start_height", v.into())));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("refresh", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.blocks_fetched, rsp.received_money))
+    }
+
+    /// Rescan the blockchain from the wallet's creation height, e.g. when transfers are missing or the wallet cache is corrupt.
+    /// A `hard` rescan also discards the wallet's cached transaction data, including tx keys and notes, instead of keeping it across the rescan.
+    pub async fn rescan_blockchain(&self, hard: bool) -> anyhow::Result<()> {
+        let params = once(("hard", hard.into()));
+
+        self.inner
+            .request::<IgnoredAny>("rescan_blockchain", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Ask the daemon again whether each of the wallet's outputs is spent, e.g. after importing key images.
+    pub async fn rescan_spent(&self) -> anyhow::Result<()> {
+        self.inner
+            .request::<IgnoredAny>("rescan_spent", RpcParams::None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Refresh the wallet every `poll_interval` until it catches up with the daemon, yielding `(wallet_height, daemon_height)` after each refresh.
+    /// The stream ends once the wallet is synced or after the first error.
+    pub fn refresh_until_synced<'a>(
+        &'a self,
+        daemon: &'a DaemonClient,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = anyhow::Result<(u64, u64)>> + 'a {
+        stream::unfold(Some(true), move |state| async move {
+            let first = state?;
+            if !first {
+                tokio::time::sleep(poll_interval).await;
+            }
+
+            let heights: anyhow::Result<(u64, u64)> = async {
+                self.refresh(None).await?;
+                let wallet_height = self.get_height().await?.get();
+                let daemon_height = daemon.get_block_count().await?.get();
+                Ok((wallet_height, daemon_height))
+            }
+            .await;
+
+            let next = match &heights {
+                Ok((wallet_height, daemon_height)) if wallet_height < daemon_height => Some(false),
+                _ => None,
+            };
+
+            Some((heights, next))
+        })
+    }
+
+    /// Send monero to a number of recipients. Without a `priority`, the wallet's default priority is used.
+    pub async fn transfer(
+        &self,
+        destinations: HashMap<Address, u64>,
+        priority: Option<TransferPriority>,
+        options: TransferOptions,
+    ) -> anyhow::Result<TransferData> {
+        for address in destinations.keys() {
+            self.inner.check_address(address)?;
+        }
+
+        let params = empty()
+            .chain(once((
+                "destinations",
+                destinations
+                    .into_iter()
+                    .map(|(address, amount)| json!({"address": address, "amount": amount}))
+                    .collect::<Vec<Value>>()
+                    .into(),
+            )))
+            .chain(priority.map(|v| ("priority", serde_json::to_value(v).unwrap())))
+            .chain(options.account_index.map(|v| ("account_index", v.into())))
+            .chain(options.subaddr_indices.map(|v| {
+                (
+                    "subaddr_indices",
+                    v.into_iter().map(From::from).collect::<Vec<Value>>().into(),
+                )
+            }))
+            .chain(options.mixin.map(|v| ("mixin", v.into())))
+            .chain(options.ring_size.map(|v| ("ring_size", v.into())))
+            .chain(
+                options
+                    .unlock_time
+                    .map(|v| ("unlock_time", u64::from(v).into())),
+            )
+            .chain(
+                options
+                    .payment_id
+                    .map(|v| ("payment_id", serde_json::to_value(HashString(v)).unwrap())),
+            )
+            .chain(options.do_not_relay.map(|v| ("do_not_relay", v.into())))
+            .chain(once(("get_tx_key", true.into())))
+            .chain(once(("get_tx_hex", true.into())))
+            .chain(once(("get_tx_metadata", true.into())));
+
+        self.inner.request("transfer", RpcParams::map(params)).await
+    }
+
+    /// Same as `WalletClient::transfer`, but lets the wallet split the payment into several transactions when it does not fit into one,
+    /// e.g. for payouts to many recipients.
+    pub async fn transfer_split(
+        &self,
+        destinations: HashMap<Address, u64>,
+        priority: TransferPriority,
+        options: TransferOptions,
+    ) -> anyhow::Result<TransferSplitData> {
+        for address in destinations.keys() {
+            self.inner.check_address(address)?;
+        }
+
+        let params = empty()
+            .chain(once((
+                "destinations",
+                destinations
+                    .into_iter()
+                    .map(|(address, amount)| json!({"address": address, "amount": amount}))
+                    .collect::<Vec<Value>>()
+                    .into(),
+            )))
+            .chain(once(("priority", serde_json::to_value(priority).unwrap())))
+            .chain(options.account_index.map(|v| ("account_index", v.into())))
+            .chain(options.subaddr_indices.map(|v| {
+                (
+                    "subaddr_indices",
+                    v.into_iter().map(From::from).collect::<Vec<Value>>().into(),
+                )
+            }))
+            .chain(options.mixin.map(|v| ("mixin", v.into())))
+            .chain(options.ring_size.map(|v| ("ring_size", v.into())))
+            .chain(
+                options
+                    .unlock_time
+                    .map(|v| ("unlock_time", u64::from(v).into())),
+            )
+            .chain(
+                options
+                    .payment_id
+                    .map(|v| ("payment_id", serde_json::to_value(HashString(v)).unwrap())),
+            )
+            .chain(options.do_not_relay.map(|v| ("do_not_relay", v.into())))
+            .chain(once(("get_tx_keys", true.into())))
+            .chain(once(("get_tx_hex", true.into())))
+            .chain(once(("get_tx_metadata", true.into())));
+
+        self.inner
+            .request("transfer_split", RpcParams::map(params))
+            .await
+    }
+
+    /// Send the whole unlocked balance of an account to `address`, in as many transactions as needed.
+    pub async fn sweep_all(
+        &self,
+        address: Address,
+        account_index: u64,
+        options: SweepAllOptions,
+    ) -> anyhow::Result<SweepAllData> {
+        self.inner.check_address(&address)?;
+
+        let params = empty()
+            .chain(once(("address", address.to_string().into())))
+            .chain(once(("account_index", account_index.into())))
+            .chain(options.subaddr_indices.map(|v| {
+                (
+                    "subaddr_indices",
+                    v.into_iter().map(From::from).collect::<Vec<Value>>().into(),
+                )
+            }))
+            .chain(
+                options
+                    .priority
+                    .map(|v| ("priority", serde_json::to_value(v).unwrap())),
+            )
+            .chain(options.ring_size.map(|v| ("ring_size", v.into())))
+            .chain(
+                options
+                    .unlock_time
+                    .map(|v| ("unlock_time", u64::from(v).into())),
+            )
+            .chain(
+                options
+                    .payment_id
+                    .map(|v| ("payment_id", serde_json::to_value(HashString(v)).unwrap())),
+            )
+            .chain(options.do_not_relay.map(|v| ("do_not_relay", v.into())))
+            .chain(options.below_amount.map(|v| ("below_amount", v.into())))
+            .chain(once(("get_tx_keys", true.into())))
+            .chain(once(("get_tx_hex", true.into())))
+            .chain(once(("get_tx_metadata", true.into())));
+
+        self.inner
+            .request("sweep_all", RpcParams::map(params))
+            .await
+    }
+
+    /// Send the single output with the given key image to `address`, leaving the rest of the wallet untouched.
+    pub async fn sweep_single(
+        &self,
+        address: Address,
+        key_image: Vec<u8>,
+        options: SweepAllOptions,
+    ) -> anyhow::Result<SweepSingleData> {
+        self.inner.check_address(&address)?;
+
+        let params = empty()
+            .chain(once(("address", address.to_string().into())))
+            .chain(once((
+                "key_image",
+                HashString(key_image).to_string().into(),
+            )))
+            .chain(
+                options
+                    .priority
+                    .map(|v| ("priority", serde_json::to_value(v).unwrap())),
+            )
+            .chain(options.ring_size.map(|v| ("ring_size", v.into())))
+            .chain(
+                options
+                    .unlock_time
+                    .map(|v| ("unlock_time", u64::from(v).into())),
+            )
+            .chain(
+                options
+                    .payment_id
+                    .map(|v| ("payment_id", serde_json::to_value(HashString(v)).unwrap())),
+            )
+            .chain(options.do_not_relay.map(|v| ("do_not_relay", v.into())))
+            .chain(once(("get_tx_key", true.into())))
+            .chain(once(("get_tx_hex", true.into())))
+            .chain(once(("get_tx_metadata", true.into())));
+
+        self.inner
+            .request("sweep_single", RpcParams::map(params))
+            .await
+    }
+
+    /// Sweep the wallet's unmixable dust outputs, left over from before RingCT, into new transactions (`sweep_unmixable` in the wallet CLI).
+    pub async fn sweep_dust(&self, get_tx_keys: bool) -> anyhow::Result<SweepDustData> {
+        let params = empty()
+            .chain(once(("get_tx_keys", get_tx_keys.into())))
+            .chain(once(("get_tx_hex", true.into())))
+            .chain(once(("get_tx_metadata", true.into())));
+
+        self.inner
+            .request("sweep_dust", RpcParams::map(params))
+            .await
+    }
+
+    /// Relay a transaction previously created with `do_not_relay`, identified by its metadata (`TransferData::tx_metadata`). Returns the transaction hash.
+    ///
+    /// Transactions created by `transfer_split` and the sweep methods are relayed one by one, passing each entry of their `tx_metadata_list`.
+    ///
+    /// This is different from [`DaemonClient::relay_tx`], which takes hashes of transactions already in the daemon's pool.
+    ///
+    /// ```no_run
+    /// # use std::collections::HashMap;
+    /// # use monero_rpc::{TransferOptions, TransferPriority};
+    /// # async fn run(wallet: monero_rpc::WalletClient, destinations: HashMap<monero::Address, u64>) -> anyhow::Result<()> {
+    /// let options = TransferOptions {
+    ///     do_not_relay: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let transfer = wallet
+    ///     .transfer(destinations, Some(TransferPriority::Default), options)
+    ///     .await?;
+    /// let tx_hash = wallet.relay_tx(transfer.tx_metadata.0).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn relay_tx(&self, tx_metadata: Vec<u8>) -> anyhow::Result<CryptoNoteHash> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            tx_hash: HashString<CryptoNoteHash>,
+        }
+
+        let params = once(("hex", HashString(tx_metadata).to_string().into()));
+
+        self.inner
+            .request::<Rsp>("relay_tx", RpcParams::map(params))
+            .await
+            .map(|v| v.tx_hash.0)
+    }
+
+    /// Describe the transactions of an unsigned transaction set created on a read-only wallet (in cold-signing process).
+    pub async fn describe_transfer(
+        &self,
+        unsigned_txset: Vec<u8>,
+    ) -> anyhow::Result<Vec<TransferDescription>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            desc: Vec<TransferDescription>,
+        }
+
+        let params = once((
+            "unsigned_txset",
+            HashString(unsigned_txset).to_string().into(),
+        ));
+
+        self.inner
+            .request::<Rsp>("describe_transfer", RpcParams::map(params))
+            .await
+            .map(|v| v.desc)
+    }
+
+    /// Sign a transaction created on a read-only wallet (in cold-signing process).
+    pub async fn sign_transfer(
+        &self,
+        unsigned_txset: Vec<u8>,
+    ) -> anyhow::Result<SignedTransferOutput> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            signed_txset: HashString<Vec<u8>>,
+            tx_hash_list: Vec<HashString<CryptoNoteHash>>,
+            tx_raw_list: Vec<HashString<Vec<u8>>>,
+        }
+
+        impl From<Rsp> for SignedTransferOutput {
+            fn from(value: Rsp) -> Self {
+                Self {
+                    signed_txset: value.signed_txset.0,
+                    tx_hash_list: value.tx_hash_list.into_iter().map(|v| v.0).collect(),
+                    tx_raw_list: value.tx_raw_list.into_iter().map(|v| v.0).collect(),
+                }
+            }
+        }
+
+        let params = empty()
+            .chain(once((
+                "unsigned_txset",
+                serde_json::to_value(HashString(unsigned_txset)).unwrap(),
+            )))
+            .chain(once(("export_raw", true.into())));
+
+        self.inner
+            .request::<Rsp>("sign_transfer", RpcParams::map(params))
+            .await
+            .map(From::from)
+    }
+
+    /// Submit a previously signed transaction on a read-only wallet (in cold-signing process).
+    pub async fn submit_transfer(
+        &self,
+        tx_data_hex: Vec<u8>,
+    ) -> anyhow::Result<Vec<CryptoNoteHash>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            tx_hash_list: Vec<HashString<CryptoNoteHash>>,
+        }
+
+        let params = empty().chain(once((
+            "tx_data_hex",
+            HashString(tx_data_hex).to_string().into(),
+        )));
+
+        self.inner
+            .request::<Rsp>("submit_transfer", RpcParams::map(params))
+            .await
+            .map(|v| v.tx_hash_list.into_iter().map(|v| v.0).collect())
+    }
+
+    /// Returns a list of transfers.
+    pub async fn get_transfers<T>(
+        &self,
+        selector: GetTransfersSelector<T>,
+    ) -> anyhow::Result<HashMap<GetTransfersCategory, Vec<GotTransfer>>>
+    where
+        T: RangeBounds<u64> + Send,
+    {
+        let GetTransfersSelector {
+            category_selector,
+            filter_by_height,
+            account_index,
+            all_accounts,
+            subaddr_indices,
+        } = selector;
+
+        let account_index = if all_accounts { None } else { account_index };
+
+        let params = empty()
+            .chain(
+                category_selector
+                    .into_iter()
+                    .map(|(cat, b)| (cat.into(), b.into())),
+            )
+            .chain({
+                filter_by_height
+                    .map(|range| {
+                        empty()
+                            .chain(Some(("filter_by_height", true.into())))
+                            .chain({
+                                match range.start_bound() {
+                                    // `min_height` is exclusive, so starting at 0 means no lower bound.
+                                    Bound::Included(b) => b.checked_sub(1),
+                                    Bound::Excluded(b) => Some(*b),
+                                    Bound::Unbounded => None,
+                                }
+                                .map(|b| ("min_height", b.into()))
+                            })
+                            .chain({
+                                match range.end_bound() {
+                                    Bound::Included(b) => Some(*b),
+                                    Bound::Excluded(b) => Some(b.checked_sub(1).unwrap_or(0)),
+                                    Bound::Unbounded => None,
+                                }
+                                .map(|b| ("max_height", b.into()))
+                            })
+                    })
+                    .into_iter()
+                    .flatten()
+            })
+            .chain(account_index.map(|v| ("account_index", v.into())))
+            .chain(Some(("all_accounts", true.into())).filter(|_| all_accounts))
+            .chain(subaddr_indices.map(|v| ("subaddr_indices", v.into())));
+
+        self.inner
+            .request("get_transfers", RpcParams::map(params))
+            .await
+    }
+
+    /// Sum of incoming transfers to an account within a range of block heights.
+    /// Transfers still in the pool have no height yet and are only counted when the range has no upper bound.
+    /// Each transfer is counted once, even if it is returned as both pending and confirmed.
+    pub async fn total_received<T>(
+        &self,
+        account_index: u64,
+        height_range: T,
+    ) -> anyhow::Result<u64>
+    where
+        T: RangeBounds<u64> + Send,
+    {
+        let include_pool = matches!(height_range.end_bound(), Bound::Unbounded);
+
+        let transfers = self
+            .get_transfers(GetTransfersSelector {
+                category_selector: empty()
+                    .chain(once((GetTransfersCategory::In, true)))
+                    .chain(once((GetTransfersCategory::Pool, include_pool)))
+                    .collect(),
+                filter_by_height: Some(height_range),
+                account_index: Some(account_index),
+                ..Default::default()
+            })
+            .await?;
+
+        let mut seen = HashSet::new();
+
+        Ok(transfers
+            .into_iter()
+            .filter(|(category, _)| {
+                *category == GetTransfersCategory::In
+                    || (include_pool && *category == GetTransfersCategory::Pool)
+            })
+            .flat_map(|(_, transfers)| transfers)
+            .filter(|t| {
+                seen.insert((
+                    t.txid.0.clone(),
+                    t.subaddr_index.major,
+                    t.subaddr_index.minor,
+                ))
+            })
+            .map(|t| t.amount)
+            .sum())
+    }
+
+    pub async fn get_transfer(
+        &self,
+        txid: CryptoNoteHash,
+        account_index: Option<u64>,
+    ) -> anyhow::Result<Option<GotTransfer>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            transfer: GotTransfer,
+        }
+
+        let params = empty()
+            .chain(Some(("txid", HashString(txid).to_string().into())))
+            .chain(account_index.map(|v| ("account_index", v.into())));
+
+        let rsp = match self
+            .inner
+            .call("get_transfer_by_txid", RpcParams::map(params), None)
+            .await?
+        {
+            Ok(v) => serde_json::from_value::<Rsp>(v)?,
+            Err(e) => {
+                if e.code == jsonrpc_core::ErrorCode::ServerError(-8) {
+                    return Ok(None);
+                } else {
+                    return Err(e.into());
+                }
+            }
+        };
+
+        Ok(Some(rsp.transfer))
+    }
+
+    /// Check that a transaction sent by this wallet has reached the daemon's pool or a block.
+    /// Returns `false` if the wallet does not know the transaction (for instance because it was created with `do_not_relay`) or if the daemon rejected it.
+    pub async fn confirm_relayed(&self, txid: CryptoNoteHash) -> anyhow::Result<bool> {
+        Ok(self
+            .get_transfer(txid, None)
+            .await?
+            .is_some_and(|transfer| transfer.transfer_type != GetTransfersCategory::Failed))
+    }
+
+    /// Attach a note to each of `txids`, `notes[i]` going to `txids[i]`. Notes are stored in the wallet file only.
+    pub async fn set_tx_notes(
+        &self,
+        txids: Vec<CryptoNoteHash>,
+        notes: Vec<String>,
+    ) -> anyhow::Result<()> {
+        if txids.len() != notes.len() {
+            anyhow::bail!(
+                "Got {} transaction ids but {} notes",
+                txids.len(),
+                notes.len()
+            );
+        }
+
+        let params = empty()
+            .chain(once((
+                "txids",
+                txids
+                    .into_iter()
+                    .map(|v| HashString(v).to_string())
+                    .collect::<Vec<_>>()
+                    .into(),
+            )))
+            .chain(once(("notes", notes.into())));
+
+        self.inner
+            .request::<IgnoredAny>("set_tx_notes", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get the notes of `txids`, in the same order. Transactions without a note get an empty string.
+    pub async fn get_tx_notes(&self, txids: Vec<CryptoNoteHash>) -> anyhow::Result<Vec<String>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            notes: Vec<String>,
+        }
+
+        let params = once((
+            "txids",
+            txids
+                .into_iter()
+                .map(|v| HashString(v).to_string())
+                .collect::<Vec<_>>()
+                .into(),
+        ));
+
+        self.inner
+            .request::<Rsp>("get_tx_notes", RpcParams::map(params))
+            .await
+            .map(|v| v.notes)
+    }
+
+    /// Store an arbitrary key/value pair in the wallet file, overwriting any previous value of `key`.
+    pub async fn set_attribute(&self, key: String, value: String) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(once(("key", key.into())))
+            .chain(once(("value", value.into())));
+
+        self.inner
+            .request::<IgnoredAny>("set_attribute", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Get a value stored with `WalletClient::set_attribute`, `None` if `key` was never set.
+    pub async fn get_attribute(&self, key: String) -> anyhow::Result<Option<String>> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            value: String,
+        }
+
+        /// `WALLET_RPC_ERROR_CODE_ATTRIBUTE_NOT_FOUND`
+        const ATTRIBUTE_NOT_FOUND: i64 = -45;
+
+        let params = once(("key", key.into()));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("get_attribute", RpcParams::map(params))
+            .await;
+        if rsp.rpc_code() == Some(ATTRIBUTE_NOT_FOUND) {
+            return Ok(None);
+        }
+
+        rsp.map(|v| Some(v.value))
+    }
+
+    /// Export a signed set of key images.
+    /// With `all` set to `false` only the key images not exported before are returned, starting at the returned offset.
+    pub async fn export_key_images(&self, all: bool) -> anyhow::Result<ExportedKeyImages> {
+        #[derive(Deserialize)]
+        struct R {
+            key_image: HashString<Vec<u8>>,
+            signature: HashString<Vec<u8>>,
+        }
+
+        #[derive(Deserialize)]
+        struct Rsp {
+            #[serde(default)]
+            offset: u64,
+            #[serde(default)]
+            signed_key_images: Vec<R>,
+        }
+
+        impl From<Rsp> for ExportedKeyImages {
+            fn from(rsp: Rsp) -> Self {
+                Self {
+                    offset: rsp.offset,
+                    signed_key_images: rsp
+                        .signed_key_images
+                        .into_iter()
+                        .map(
+                            |R {
+                                 key_image,
+                                 signature,
+                             }| SignedKeyImage {
+                                key_image: key_image.0,
+                                signature: signature.0,
+                            },
+                        )
+                        .collect(),
+                }
+            }
+        }
+
+        let params = empty().chain(once(("all", all.into())));
+
+        self.inner
+            .request::<Rsp>("export_key_images", RpcParams::map(params))
+            .await
+            .map(From::from)
     }
 
-    /// Returns the wallet's current block height.
-    pub async fn get_height(&self) -> anyhow::Result<NonZeroU64> {
+    /// Import signed key images list and verify their spent status.
+    /// `offset` is the position in the wallet's key image list at which the provided signed key images start, allowing a partial export to be imported.
+    pub async fn import_key_images(
+        &self,
+        signed_key_images: Vec<SignedKeyImage>,
+        offset: u64,
+    ) -> anyhow::Result<KeyImageImportResponse> {
+        let params = empty().chain(once(("offset", offset.into()))).chain(once((
+            "signed_key_images",
+            signed_key_images
+                .into_iter()
+                .map(
+                    |SignedKeyImage {
+                         key_image,
+                         signature,
+                     }| {
+                        json!({
+                            "key_image": HashString(key_image),
+                            "signature": HashString(signature),
+                        })
+                    },
+                )
+                .collect::<Vec<_>>()
+                .into(),
+        )));
+
+        self.inner
+            .request("import_key_images", RpcParams::map(params))
+            .await
+    }
+
+    /// Check a tx_key is valid given a txid and receiver address.
+    pub async fn check_tx_key(
+        &self,
+        txid: CryptoNoteHash,
+        tx_key: CryptoNoteHash,
+        address: Address,
+    ) -> anyhow::Result<(NonZeroU64, bool, NonZeroU64)> {
         #[derive(Deserialize)]
         struct Rsp {
-            height: NonZeroU64,
+            confirmations: NonZeroU64,
+            in_pool: bool,
+            received: NonZeroU64,
         }
 
-        Ok(self
+        self.inner.check_address(&address)?;
+
+        let params = empty()
+            .chain(once(("txid", HashString(txid).to_string().into())))
+            .chain(once(("tx_key", HashString(tx_key).to_string().into())))
+            .chain(once(("address", address.to_string().into())));
+
+        let rsp = self
             .inner
-            .request::<Rsp>("get_height", RpcParams::None)
-            .await?
-            .height)
+            .request::<Rsp>("check_tx_key", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.confirmations, rsp.in_pool, rsp.received))
     }
 
-    /// Send monero to a number of recipients.
-    pub async fn transfer(
+    /// Start mining in the daemon the wallet is connected to, rewarding the wallet's primary address.
+    pub async fn start_mining(
         &self,
-        destinations: HashMap<Address, u64>,
-        priority: TransferPriority,
-        options: TransferOptions,
-    ) -> anyhow::Result<TransferData> {
+        threads_count: u64,
+        do_background_mining: bool,
+        ignore_battery: bool,
+    ) -> anyhow::Result<()> {
         let params = empty()
-            .chain(once((
-                "destinations",
-                destinations
-                    .into_iter()
-                    .map(|(address, amount)| json!({"address": address, "amount": amount}))
-                    .collect::<Vec<Value>>()
-                    .into(),
-            )))
-            .chain(once(("priority", serde_json::to_value(priority)?)))
-            .chain(options.account_index.map(|v| ("account_index", v.into())))
-            .chain(options.subaddr_indices.map(|v| {
-                (
-                    "subaddr_indices",
-                    v.into_iter().map(From::from).collect::<Vec<Value>>().into(),
-                )
-            }))
-            .chain(options.mixin.map(|v| ("mixin", v.into())))
-            .chain(options.ring_size.map(|v| ("ring_size", v.into())))
-            .chain(options.unlock_time.map(|v| ("unlock_time", v.into())))
-            .chain(
-                options
-                    .payment_id
-                    .map(|v| ("payment_id", serde_json::to_value(HashString(v)).unwrap())),
-            )
-            .chain(options.do_not_relay.map(|v| ("do_not_relay", v.into())))
-            .chain(once(("get_tx_key", true.into())))
-            .chain(once(("get_tx_hex", true.into())))
-            .chain(once(("get_tx_metadata", true.into())));
+            .chain(once(("threads_count", threads_count.into())))
+            .chain(once(("do_background_mining", do_background_mining.into())))
+            .chain(once(("ignore_battery", ignore_battery.into())));
 
-        self.inner.request("transfer", RpcParams::map(params)).await
+        self.inner
+            .request::<IgnoredAny>("start_mining", RpcParams::map(params))
+            .await?;
+
+        Ok(())
     }
 
-    /// Sign a transaction created on a read-only wallet (in cold-signing process).
-    pub async fn sign_transfer(
+    /// Stop mining in the daemon the wallet is connected to.
+    pub async fn stop_mining(&self) -> anyhow::Result<()> {
+        self.inner
+            .request::<IgnoredAny>("stop_mining", RpcParams::None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Generate a signature proving that the wallet sent a transaction to `address`, or received it if `address` is one of the wallet's own.
+    pub async fn get_tx_proof(
         &self,
-        unsigned_txset: Vec<u8>,
-    ) -> anyhow::Result<SignedTransferOutput> {
+        txid: CryptoNoteHash,
+        address: Address,
+        message: Option<String>,
+    ) -> anyhow::Result<String> {
         #[derive(Deserialize)]
         struct Rsp {
-            signed_txset: HashString<Vec<u8>>,
-            tx_hash_list: Vec<HashString<CryptoNoteHash>>,
-            tx_raw_list: Vec<HashString<Vec<u8>>>,
+            signature: String,
         }
 
-        impl From<Rsp> for SignedTransferOutput {
-            fn from(value: Rsp) -> Self {
-                Self {
-                    signed_txset: value.signed_txset.0,
-                    tx_hash_list: value.tx_hash_list.into_iter().map(|v| v.0).collect(),
-                    tx_raw_list: value.tx_raw_list.into_iter().map(|v| v.0).collect(),
-                }
-            }
+        self.inner.check_address(&address)?;
+
+        let params = empty()
+            .chain(once(("txid", HashString(txid).to_string().into())))
+            .chain(once(("address", address.to_string().into())))
+            .chain(message.map(|v| ("message", v.into())));
+
+        self.inner
+            .request::<Rsp>("get_tx_proof", RpcParams::map(params))
+            .await
+            .map(|v| v.signature)
+    }
+
+    /// Check a signature made by `WalletClient::get_tx_proof`, proving that transaction `txid` paid `address`.
+    pub async fn check_tx_proof(
+        &self,
+        txid: CryptoNoteHash,
+        address: Address,
+        message: Option<String>,
+        signature: String,
+    ) -> anyhow::Result<CheckTxProof> {
+        self.inner.check_address(&address)?;
+
+        let params = empty()
+            .chain(once(("txid", HashString(txid).to_string().into())))
+            .chain(once(("address", address.to_string().into())))
+            .chain(message.map(|v| ("message", v.into())))
+            .chain(once(("signature", signature.into())));
+
+        self.inner
+            .request("check_tx_proof", RpcParams::map(params))
+            .await
+    }
+
+    /// Generate a signature proving that the wallet created transaction `txid`, without revealing its recipient.
+    pub async fn get_spend_proof(
+        &self,
+        txid: CryptoNoteHash,
+        message: Option<String>,
+    ) -> anyhow::Result<String> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            signature: String,
         }
 
         let params = empty()
-            .chain(once((
-                "unsigned_txset",
-                serde_json::to_value(HashString(unsigned_txset)).unwrap(),
-            )))
-            .chain(once(("export_raw", true.into())));
+            .chain(once(("txid", HashString(txid).to_string().into())))
+            .chain(message.map(|v| ("message", v.into())));
 
         self.inner
-            .request::<Rsp>("sign_transfer", RpcParams::map(params))
+            .request::<Rsp>("get_spend_proof", RpcParams::map(params))
             .await
-            .map(From::from)
+            .map(|v| v.signature)
     }
 
-    /// Submit a previously signed transaction on a read-only wallet (in cold-signing process).
-    pub async fn submit_transfer(
+    /// Check a signature made by `WalletClient::get_spend_proof`.
+    pub async fn check_spend_proof(
         &self,
-        tx_data_hex: Vec<u8>,
-    ) -> anyhow::Result<Vec<CryptoNoteHash>> {
+        txid: CryptoNoteHash,
+        message: Option<String>,
+        signature: String,
+    ) -> anyhow::Result<bool> {
         #[derive(Deserialize)]
         struct Rsp {
-            tx_hash_list: Vec<HashString<CryptoNoteHash>>,
+            good: bool,
         }
 
-        let params = empty().chain(once((
-            "tx_data_hex",
-            HashString(tx_data_hex).to_string().into(),
-        )));
+        let params = empty()
+            .chain(once(("txid", HashString(txid).to_string().into())))
+            .chain(message.map(|v| ("message", v.into())))
+            .chain(once(("signature", signature.into())));
 
         self.inner
-            .request::<Rsp>("submit_transfer", RpcParams::map(params))
+            .request::<Rsp>("check_spend_proof", RpcParams::map(params))
             .await
-            .map(|v| v.tx_hash_list.into_iter().map(|v| v.0).collect())
+            .map(|v| v.good)
     }
 
-    /// Returns a list of transfers.
-    pub async fn get_transfers<T>(
+    /// Generate a signature proving that the wallet holds the given amount in an account, or the whole balance of the wallet if `all` is set (`account_index` and `amount` are then ignored).
+    ///
+    /// The wallet has to check every output it owns, so on large wallets this can take several minutes.
+    /// Pass a `timeout` to override the HTTP client's timeout for this call only.
+    pub async fn get_reserve_proof(
         &self,
-        selector: GetTransfersSelector<T>,
-    ) -> anyhow::Result<HashMap<GetTransfersCategory, Vec<GotTransfer>>>
-    where
-        T: RangeBounds<u64> + Send,
-    {
-        let GetTransfersSelector {
-            category_selector,
-            filter_by_height,
-            account_index,
-            subaddr_indices,
-        } = selector;
+        all: bool,
+        account_index: u64,
+        amount: u64,
+        message: Option<String>,
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<String> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            signature: String,
+        }
 
         let params = empty()
-            .chain(
-                category_selector
-                    .into_iter()
-                    .map(|(cat, b)| (cat.into(), b.into())),
-            )
-            .chain({
-                filter_by_height
-                    .map(|range| {
-                        empty()
-                            .chain(Some(("filter_by_height", true.into())))
-                            .chain({
-                                match range.start_bound() {
-                                    Bound::Included(b) => Some(b - 1),
-                                    Bound::Excluded(b) => Some(*b),
-                                    Bound::Unbounded => None,
-                                }
-                                .map(|b| ("min_height", b.into()))
-                            })
-                            .chain({
-                                match range.end_bound() {
-                                    Bound::Included(b) => Some(*b),
-                                    Bound::Excluded(b) => Some(b.checked_sub(1).unwrap_or(0)),
-                                    Bound::Unbounded => None,
-                                }
-                                .map(|b| ("max_height", b.into()))
-                            })
-                    })
-                    .into_iter()
-                    .flatten()
-            })
-            .chain(account_index.map(|v| ("account_index", v.into())))
-            .chain(subaddr_indices.map(|v| ("subaddr_indices", v.into())));
+            .chain(once(("all", all.into())))
+            .chain(once(("account_index", account_index.into())))
+            .chain(once(("amount", amount.into())))
+            .chain(message.map(|v| ("message", v.into())));
+
+        self.inner
+            .request_with_timeout::<Rsp>("get_reserve_proof", RpcParams::map(params), timeout)
+            .await
+            .map(|v| v.signature)
+    }
+
+    /// Check a signature made by `WalletClient::get_reserve_proof` for `address`. Returns `(good, total, spent)`,
+    /// where `total` is the proven amount in piconero and `spent` the part of it already spent.
+    pub async fn check_reserve_proof(
+        &self,
+        address: Address,
+        message: Option<String>,
+        signature: String,
+    ) -> anyhow::Result<(bool, u64, u64)> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            good: bool,
+            #[serde(default)]
+            total: u64,
+            #[serde(default)]
+            spent: u64,
+        }
+
+        self.inner.check_address(&address)?;
+
+        let params = empty()
+            .chain(once(("address", address.to_string().into())))
+            .chain(message.map(|v| ("message", v.into())))
+            .chain(once(("signature", signature.into())));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("check_reserve_proof", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.good, rsp.total, rsp.spent))
+    }
+
+    /// Collect a tx proof for each of `txids` towards `address` and a reserve proof of the whole balance into a single [`ProofBundle`], all signed over `message`.
+    ///
+    /// The reserve proof has the same cost as `get_reserve_proof` with `all` set.
+    pub async fn export_proof_bundle(
+        &self,
+        txids: Vec<CryptoNoteHash>,
+        address: Address,
+        message: String,
+    ) -> anyhow::Result<ProofBundle> {
+        let mut tx_proofs = Vec::with_capacity(txids.len());
+        for txid in txids {
+            let signature = self
+                .get_tx_proof(txid, address, Some(message.clone()))
+                .await?;
+            tx_proofs.push(TxProof {
+                txid: HashString(txid),
+                signature,
+            });
+        }
+
+        let reserve_proof = self
+            .get_reserve_proof(true, 0, 0, Some(message.clone()), None)
+            .await?;
+        let height = self.get_height().await?.get();
+
+        Ok(ProofBundle {
+            address,
+            message,
+            height,
+            created_at: chrono::Utc::now(),
+            tx_proofs,
+            reserve_proof,
+        })
+    }
+
+    /// Sign `data` with the wallet's spend key. The returned signature (`SigV1...` or `SigV2...`) is meant to be passed to `verify_signature` as is.
+    pub async fn sign(&self, data: String) -> anyhow::Result<String> {
+        #[derive(Deserialize)]
+        struct Rsp {
+            signature: String,
+        }
+
+        let params = once(("data", data.into()));
 
         self.inner
-            .request("get_transfers", RpcParams::map(params))
+            .request::<Rsp>("sign", RpcParams::map(params))
             .await
+            .map(|v| v.signature)
     }
 
-    pub async fn get_transfer(
+    /// Check that `signature` was made over `data` by the owner of `address`, see `WalletClient::sign`.
+    ///
+    /// This is the `verify` RPC, named differently because `WalletClient::verify` checks the endpoint.
+    pub async fn verify_signature(
         &self,
-        txid: CryptoNoteHash,
-        account_index: Option<u64>,
-    ) -> anyhow::Result<Option<GotTransfer>> {
+        data: String,
+        address: Address,
+        signature: String,
+    ) -> anyhow::Result<bool> {
         #[derive(Deserialize)]
         struct Rsp {
-            transfer: GotTransfer,
+            good: bool,
         }
 
-        let params = empty()
-            .chain(Some(("txid", HashString(txid).to_string().into())))
-            .chain(account_index.map(|v| ("account_index", v.into())));
+        self.inner.check_address(&address)?;
 
-        let rsp = match self
-            .inner
-            .0
-            .call("get_transfer_by_txid", RpcParams::map(params))
-            .await?
-        {
-            Ok(v) => serde_json::from_value::<Rsp>(v)?,
-            Err(e) => {
-                if e.code == jsonrpc_core::ErrorCode::ServerError(-8) {
-                    return Ok(None);
-                } else {
-                    return Err(e.into());
-                }
-            }
-        };
+        let params = empty()
+            .chain(once(("data", data.into())))
+            .chain(once(("address", address.to_string().into())))
+            .chain(once(("signature", signature.into())));
 
-        Ok(Some(rsp.transfer))
+        self.inner
+            .request::<Rsp>("verify", RpcParams::map(params))
+            .await
+            .map(|v| v.good)
     }
 
-    /// Export a signed set of key images.
-    pub async fn export_key_images(&self) -> anyhow::Result<Vec<SignedKeyImage>> {
+    /// Check many tx_keys in a single batch, see `WalletClient::check_tx_key`.
+    /// Items are `(txid, tx_key, address)`, results are `(confirmations, in_pool, received)` in the same order. A failed check does not abort the others.
+    /// If the server rejects batches, as monero-wallet-rpc does, the keys are checked with concurrent single calls instead.
+    pub async fn check_tx_keys(
+        &self,
+        items: Vec<(CryptoNoteHash, CryptoNoteHash, Address)>,
+    ) -> anyhow::Result<Vec<anyhow::Result<(u64, bool, u64)>>> {
         #[derive(Deserialize)]
-        struct R {
-            key_image: HashString<Vec<u8>>,
-            signature: HashString<Vec<u8>>,
+        struct Rsp {
+            confirmations: u64,
+            in_pool: bool,
+            received: u64,
+        }
+
+        for (_, _, address) in &items {
+            self.inner.check_address(address)?;
         }
 
+        let params = |(txid, tx_key, address): &(CryptoNoteHash, CryptoNoteHash, Address)| {
+            RpcParams::map(
+                empty()
+                    .chain(once(("txid", HashString(*txid).to_string().into())))
+                    .chain(once(("tx_key", HashString(*tx_key).to_string().into())))
+                    .chain(once(("address", address.to_string().into()))),
+            )
+        };
+
+        let rsp = self
+            .inner
+            .request_batch::<Rsp>("check_tx_key", items.iter().map(params).collect())
+            .await;
+        let rsp = if rsp.rpc_code().is_some() {
+            join_all(
+                items
+                    .iter()
+                    .map(|item| self.inner.request::<Rsp>("check_tx_key", params(item))),
+            )
+            .await
+        } else {
+            rsp?
+        };
+
+        Ok(rsp
+            .into_iter()
+            .map(|rsp| rsp.map(|v| (v.confirmations, v.in_pool, v.received)))
+            .collect())
+    }
+
+    /// Estimate the size and weight of a transaction with the given number of inputs and outputs. Returns `(size, weight)`.
+    pub async fn estimate_tx_size_and_weight(
+        &self,
+        n_inputs: u64,
+        n_outputs: u64,
+        ring_size: u64,
+        rct: bool,
+    ) -> anyhow::Result<(u64, u64)> {
         #[derive(Deserialize)]
         struct Rsp {
-            #[serde(default)]
-            signed_key_images: Vec<R>,
+            size: u64,
+            weight: u64,
         }
 
-        impl From<Rsp> for Vec<SignedKeyImage> {
-            fn from(rsp: Rsp) -> Self {
-                rsp.signed_key_images
-                    .into_iter()
-                    .map(
-                        |R {
-                             key_image,
-                             signature,
-                         }| SignedKeyImage {
-                            key_image: key_image.0,
-                            signature: signature.0,
-                        },
-                    )
-                    .collect()
-            }
+        if n_inputs == 0 || n_outputs == 0 {
+            anyhow::bail!("A transaction needs at least one input and one output");
         }
 
         self.inner
-            .request::<Rsp>("export_key_images", RpcParams::None)
-            .await
-            .map(From::from)
+            .require_version(
+                "estimate_tx_size_and_weight",
+                ESTIMATE_TX_SIZE_AND_WEIGHT_VERSION,
+            )
+            .await?;
+
+        let params = empty()
+            .chain(once(("n_inputs", n_inputs.into())))
+            .chain(once(("n_outputs", n_outputs.into())))
+            .chain(once(("ring_size", ring_size.into())))
+            .chain(once(("rct", rct.into())));
+
+        let rsp = self
+            .inner
+            .request::<Rsp>("estimate_tx_size_and_weight", RpcParams::map(params))
+            .await?;
+
+        Ok((rsp.size, rsp.weight))
     }
 
-    /// Import signed key images list and verify their spent status.
-    pub async fn import_key_images(
+    /// Estimate the fee in piconero of a RingCT transaction with the given number of inputs and outputs.
+    /// The estimated weight is multiplied by the daemon's per-byte fee for `priority` and rounded up to the daemon's quantization mask.
+    pub async fn estimate_fee_for(
         &self,
-        signed_key_images: Vec<SignedKeyImage>,
-    ) -> anyhow::Result<KeyImageImportResponse> {
-        let params = empty().chain(once((
-            "signed_key_images",
-            signed_key_images
-                .into_iter()
-                .map(
-                    |SignedKeyImage {
-                         key_image,
-                         signature,
-                     }| {
-                        json!({
-                            "key_image": HashString(key_image),
-                            "signature": HashString(signature),
-                        })
-                    },
+        n_inputs: u64,
+        n_outputs: u64,
+        ring_size: u64,
+        priority: TransferPriority,
+        daemon: &DaemonClient,
+    ) -> anyhow::Result<u64> {
+        let (_, weight) = self
+            .estimate_tx_size_and_weight(n_inputs, n_outputs, ring_size, true)
+            .await?;
+        let estimate = daemon.get_fee_estimate(None).await?;
+
+        let fee_per_byte = estimate.for_priority(priority);
+        let mask = estimate.quantization_mask.max(1);
+
+        weight
+            .checked_mul(fee_per_byte)
+            .and_then(|fee| fee.div_ceil(mask).checked_mul(mask))
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Fee for weight {} at {} piconero per byte overflows",
+                    weight,
+                    fee_per_byte
                 )
-                .collect::<Vec<_>>()
-                .into(),
-        )));
+            })
+    }
+
+    /// Point the wallet at another daemon. Use `ssl_allowed_fingerprints` to pin the daemon's certificate.
+    pub async fn set_daemon(
+        &self,
+        address: String,
+        options: SetDaemonOptions,
+    ) -> anyhow::Result<()> {
+        let SetDaemonOptions {
+            trusted,
+            ssl_support,
+            ssl_allowed_fingerprints,
+            ssl_allow_any_cert,
+            ssl_ca_file,
+            username,
+            password,
+        } = options;
 
         self.inner
-            .request("import_key_images", RpcParams::map(params))
-            .await
+            .require_version("set_daemon", SET_DAEMON_VERSION)
+            .await?;
+
+        let params = empty()
+            .chain(once(("address", address.into())))
+            .chain(trusted.map(|v| ("trusted", v.into())))
+            .chain(ssl_support.map(|v| ("ssl_support", serde_json::to_value(v).unwrap())))
+            .chain(
+                Some(ssl_allowed_fingerprints)
+                    .filter(|v| !v.is_empty())
+                    .map(|v| ("ssl_allowed_fingerprints", v.into())),
+            )
+            .chain(Some(("ssl_allow_any_cert", true.into())).filter(|_| ssl_allow_any_cert))
+            .chain(ssl_ca_file.map(|v| ("ssl_ca_file", v.into())))
+            .chain(username.map(|v| ("username", v.into())))
+            .chain(password.map(|v| ("password", v.into())));
+
+        self.inner
+            .request::<IgnoredAny>("set_daemon", RpcParams::map(params))
+            .await?;
+
+        Ok(())
     }
 
-    /// Check a tx_key is valid given a txid and receiver address.
-    pub async fn check_tx_key(
+    /// Create a wallet file in the wallet RPC server's `--wallet-dir` and open it, saving and closing the currently open wallet first.
+    /// `language` is the language of the mnemonic seed, e.g. `"English"`.
+    pub async fn create_wallet(
         &self,
-        txid: CryptoNoteHash,
-        tx_key: CryptoNoteHash,
+        filename: String,
+        password: Option<String>,
+        language: String,
+    ) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(once(("filename", filename.into())))
+            .chain(password.map(|v| ("password", v.into())))
+            .chain(once(("language", language.into())));
+
+        self.inner
+            .request::<IgnoredAny>("create_wallet", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Create a wallet file in the wallet RPC server's `--wallet-dir` from existing keys and open it.
+    /// Without a `spendkey`, the wallet is view-only. The wallet scans from `restore_height`, or from the genesis block if `None`.
+    pub async fn generate_from_keys(
+        &self,
+        filename: String,
         address: Address,
-    ) -> anyhow::Result<(NonZeroU64, bool, NonZeroU64)> {
-        #[derive(Deserialize)]
-        struct Rsp {
-            confirmations: NonZeroU64,
-            in_pool: bool,
-            received: NonZeroU64,
-        }
+        spendkey: Option<monero::PrivateKey>,
+        viewkey: monero::PrivateKey,
+        password: String,
+        restore_height: Option<u64>,
+    ) -> anyhow::Result<GenerateFromKeysData> {
+        self.inner.check_address(&address)?;
+        self.inner
+            .require_version("generate_from_keys", GENERATE_FROM_KEYS_VERSION)
+            .await?;
 
         let params = empty()
-            .chain(once(("txid", HashString(txid).to_string().into())))
-            .chain(once(("tx_key", HashString(tx_key).to_string().into())))
-            .chain(once(("address", address.to_string().into())));
+            .chain(once(("filename", filename.into())))
+            .chain(once(("address", address.to_string().into())))
+            .chain(spendkey.map(|v| ("spendkey", v.to_string().into())))
+            .chain(once(("viewkey", viewkey.to_string().into())))
+            .chain(once(("password", password.into())))
+            .chain(restore_height.map(|v| ("restore_height", v.into())));
 
-        let rsp = self
-            .inner
-            .request::<Rsp>("check_tx_key", RpcParams::map(params))
+        self.inner
+            .request("generate_from_keys", RpcParams::map(params))
+            .await
+    }
+
+    /// Create a wallet file in the wallet RPC server's `--wallet-dir` from a mnemonic seed and open it.
+    /// `language` is only needed for seeds in the deprecated format, `seed_offset` is the passphrase the seed was extended with, if any.
+    pub async fn restore_deterministic_wallet(
+        &self,
+        filename: String,
+        seed: String,
+        password: String,
+        restore_height: Option<u64>,
+        language: Option<String>,
+        seed_offset: Option<String>,
+    ) -> anyhow::Result<RestoreDeterministicWalletData> {
+        self.inner
+            .require_version(
+                "restore_deterministic_wallet",
+                RESTORE_DETERMINISTIC_WALLET_VERSION,
+            )
             .await?;
 
-        Ok((rsp.confirmations, rsp.in_pool, rsp.received))
+        let params = empty()
+            .chain(once(("filename", filename.into())))
+            .chain(once(("seed", seed.into())))
+            .chain(once(("password", password.into())))
+            .chain(restore_height.map(|v| ("restore_height", v.into())))
+            .chain(language.map(|v| ("language", v.into())))
+            .chain(seed_offset.map(|v| ("seed_offset", v.into())));
+
+        self.inner
+            .request("restore_deterministic_wallet", RpcParams::map(params))
+            .await
+    }
+
+    /// Open a wallet file from the wallet RPC server's `--wallet-dir`, saving and closing the currently open wallet first.
+    pub async fn open_wallet(
+        &self,
+        filename: String,
+        password: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.open_wallet_with_options(filename, password, true)
+            .await
+    }
+
+    /// Same as `WalletClient::open_wallet`, with `autosave_current` controlling whether the currently open wallet is saved before being closed.
+    /// Skipping the save makes switching faster, but loses state not yet saved by the periodic autosave.
+    pub async fn open_wallet_with_options(
+        &self,
+        filename: String,
+        password: Option<String>,
+        autosave_current: bool,
+    ) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(once(("filename", filename.into())))
+            .chain(password.map(|v| ("password", v.into())))
+            .chain(once(("autosave_current", autosave_current.into())));
+
+        self.inner
+            .request::<IgnoredAny>("open_wallet", RpcParams::map(params))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Save the wallet file now instead of waiting for the periodic autosave, e.g. before shutting the wallet RPC server down.
+    pub async fn store(&self) -> anyhow::Result<()> {
+        self.inner
+            .request::<IgnoredAny>("store", RpcParams::None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Save and close the currently open wallet. Wallet methods fail until another wallet is opened.
+    pub async fn close_wallet(&self) -> anyhow::Result<()> {
+        self.inner
+            .request::<IgnoredAny>("close_wallet", RpcParams::None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Change the password of the currently open wallet. `None` stands for an empty password, for wallets without one or to remove it.
+    pub async fn change_wallet_password(
+        &self,
+        old_password: Option<String>,
+        new_password: Option<String>,
+    ) -> anyhow::Result<()> {
+        let params = empty()
+            .chain(old_password.map(|v| ("old_password", v.into())))
+            .chain(new_password.map(|v| ("new_password", v.into())));
+
+        self.inner
+            .request::<IgnoredAny>("change_wallet_password", RpcParams::map(params))
+            .await?;
+
+        Ok(())
     }
 
     /// Get RPC version Major & Minor integer-format, where Major is the first 16 bits and Minor the last 16 bits.
@@ -837,3 +3622,334 @@ impl WalletClient {
         Ok((u16::try_from(major)?, u16::try_from(minor)?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    type Respond = dyn Fn(&str, &Value) -> jsonrpc_core::Result<Value> + Send + Sync;
+
+    /// Caller answering from a closure instead of a server, recording the calls made.
+    struct MockCaller {
+        respond: Box<Respond>,
+        calls: Mutex<Vec<(&'static str, Value)>>,
+        /// Answer batches with a single parse error, like monerod and monero-wallet-rpc.
+        rejects_batches: AtomicBool,
+    }
+
+    impl Debug for MockCaller {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("MockCaller").finish()
+        }
+    }
+
+    impl MockCaller {
+        fn answer(&self, method: &'static str, params: RpcParams) -> jsonrpc_core::Result<Value> {
+            let params = match Params::from(params) {
+                Params::Map(v) => Value::Object(v),
+                Params::Array(v) => Value::Array(v),
+                Params::None => Value::Null,
+            };
+            let rsp = (self.respond)(method, &params);
+            self.calls.lock().unwrap().push((method, params));
+            rsp
+        }
+
+        fn calls(&self) -> Vec<(&'static str, Value)> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl JsonRpcCaller for MockCaller {
+        async fn call(
+            &self,
+            method: &'static str,
+            params: RpcParams,
+            _timeout: Option<Duration>,
+        ) -> anyhow::Result<jsonrpc_core::Result<Value>> {
+            Ok(self.answer(method, params))
+        }
+
+        async fn call_batch(
+            &self,
+            calls: Vec<(&'static str, RpcParams)>,
+        ) -> anyhow::Result<Vec<jsonrpc_core::Result<Value>>> {
+            if self.rejects_batches.load(Ordering::SeqCst) {
+                return Err(jsonrpc_core::Error::parse_error().into());
+            }
+
+            Ok(calls
+                .into_iter()
+                .map(|(method, params)| self.answer(method, params))
+                .collect())
+        }
+
+        async fn call_other(&self, path: &'static str, params: RpcParams) -> anyhow::Result<Value> {
+            Ok(self.answer(path, params)?)
+        }
+    }
+
+    fn mock<F>(respond: F) -> (RpcClient, Arc<MockCaller>)
+    where
+        F: Fn(&str, &Value) -> jsonrpc_core::Result<Value> + Send + Sync + 'static,
+    {
+        let caller = Arc::new(MockCaller {
+            respond: Box::new(respond),
+            calls: Default::default(),
+            rejects_batches: Default::default(),
+        });
+        let client = RpcClient {
+            inner: CallerWrapper {
+                caller: caller.clone(),
+                network: None,
+                cancellation: None,
+                version: Default::default(),
+            },
+        };
+
+        (client, caller)
+    }
+
+    #[tokio::test]
+    async fn cached_fee_estimate_per_grace_blocks() {
+        let (client, caller) = mock(|_, params| {
+            Ok(json!({
+                "status": "OK",
+                "fee": params["grace_blocks"].as_u64().unwrap_or(1),
+                "fees": [],
+                "quantization_mask": 10000,
+            }))
+        });
+        let daemon = client.daemon().cached(Duration::from_secs(60));
+
+        assert_eq!(daemon.get_fee_estimate(Some(10)).await.unwrap().fee, 10);
+        assert_eq!(daemon.get_fee_estimate(None).await.unwrap().fee, 1);
+        assert_eq!(daemon.get_fee_estimate(Some(10)).await.unwrap().fee, 10);
+        assert_eq!(caller.calls().len(), 2);
+    }
+
+    fn address() -> Address {
+        let view = monero::PrivateKey::from_slice(&[1; 32]).unwrap();
+        let spend = monero::PrivateKey::from_slice(&[2; 32]).unwrap();
+
+        Address::standard(
+            monero::Network::Mainnet,
+            monero::PublicKey::from_private_key(&spend),
+            monero::PublicKey::from_private_key(&view),
+        )
+    }
+
+    fn transfer(txid: u8, amount: u64, height: u64, transfer_type: &str) -> Value {
+        json!({
+            "address": address().to_string(),
+            "amount": amount,
+            "double_spend_seen": false,
+            "fee": 0,
+            "height": height,
+            "note": "",
+            "payment_id": "0000000000000000",
+            "subaddr_index": {"major": 0, "minor": 0},
+            "timestamp": 1_600_000_000,
+            "txid": hex::encode([txid; 32]),
+            "type": transfer_type,
+            "unlock_time": 0,
+        })
+    }
+
+    #[tokio::test]
+    async fn total_received_from_genesis() {
+        let (client, caller) = mock(|_, params| {
+            let mut rsp = json!({ "in": [transfer(1, 100, 5, "in"), transfer(2, 20, 8, "in")] });
+            if params["pool"] == true {
+                rsp["pool"] = json!([transfer(3, 50, 0, "pool"), transfer(3, 50, 0, "pool")]);
+            }
+            Ok(rsp)
+        });
+        let wallet = client.wallet();
+
+        assert_eq!(wallet.total_received(0, 0..).await.unwrap(), 170);
+        assert_eq!(wallet.total_received(0, 0..=10).await.unwrap(), 120);
+        assert_eq!(wallet.total_received(0, 5..).await.unwrap(), 170);
+
+        let calls = caller.calls();
+        assert_eq!(calls[0].1.get("min_height"), None);
+        assert_eq!(calls[0].1.get("max_height"), None);
+        assert_eq!(calls[1].1.get("min_height"), None);
+        assert_eq!(calls[1].1["max_height"], 10);
+        assert_eq!(calls[2].1["min_height"], 4);
+    }
+
+    #[tokio::test]
+    async fn address_ranges() {
+        let (client, caller) = mock(|_, params| {
+            let indices = match params.get("address_index") {
+                Some(v) => v
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|v| v.as_u64().unwrap())
+                    .collect(),
+                None => (0..5).collect::<Vec<_>>(),
+            };
+            Ok(json!({
+                "address": address().to_string(),
+                "addresses": indices.into_iter().map(|i| json!({
+                    "address": address().to_string(),
+                    "address_index": i,
+                    "label": "",
+                    "used": false,
+                })).collect::<Vec<_>>(),
+            }))
+        });
+        let wallet = client.wallet();
+        let indices =
+            |v: Vec<SubaddressData>| v.into_iter().map(|v| v.address_index).collect::<Vec<_>>();
+
+        assert!(wallet.get_address_range(0, 0..0).await.unwrap().is_empty());
+        assert!(wallet
+            .get_address_range(0, (Bound::Included(5), Bound::Excluded(0)))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(wallet
+            .get_address_range(0, (Bound::Excluded(u64::MAX), Bound::Unbounded))
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(caller.calls().is_empty());
+
+        assert_eq!(
+            indices(wallet.get_address_range(0, 1..3).await.unwrap()),
+            [1, 2]
+        );
+        assert_eq!(caller.calls()[0].1["address_index"], json!([1, 2]));
+
+        assert_eq!(
+            indices(wallet.get_address_range(0, 3..).await.unwrap()),
+            [3, 4]
+        );
+        assert_eq!(
+            indices(wallet.get_address_range(0, ..=u64::MAX).await.unwrap()),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            indices(wallet.get_address_range(0, 2..=1_000_000).await.unwrap()),
+            [2, 3, 4]
+        );
+        assert!(caller.calls()[1..]
+            .iter()
+            .all(|(_, params)| params.get("address_index").is_none()));
+    }
+
+    #[tokio::test]
+    async fn confirm_relayed_by_transfer_type() {
+        let (client, _) = mock(|_, params| {
+            let txid = params["txid"].as_str().unwrap();
+            match txid.as_bytes()[1] {
+                b'1' => Ok(json!({ "transfer": transfer(1, 10, 0, "pending") })),
+                b'2' => Ok(json!({ "transfer": transfer(2, 10, 7, "out") })),
+                b'3' => Ok(json!({ "transfer": transfer(3, 10, 0, "failed") })),
+                _ => Err(jsonrpc_core::Error {
+                    code: ErrorCode::ServerError(-8),
+                    message: "Transaction not found.".into(),
+                    data: None,
+                }),
+            }
+        });
+        let wallet = client.wallet();
+        let txid = |b: u8| CryptoNoteHash::from_slice(&[b; 32]);
+
+        assert!(wallet.confirm_relayed(txid(1)).await.unwrap());
+        assert!(wallet.confirm_relayed(txid(2)).await.unwrap());
+        assert!(!wallet.confirm_relayed(txid(3)).await.unwrap());
+        assert!(!wallet.confirm_relayed(txid(4)).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn create_addresses_requires_count_support() {
+        let (client, caller) = mock(|method, _| match method {
+            "get_version" => Ok(json!({ "version": (1 << 16) | 13 })),
+            _ => Ok(json!({
+                "address": address().to_string(),
+                "address_index": 1,
+            })),
+        });
+        let wallet = client.wallet();
+
+        let err = wallet.create_addresses(0, 2, None).await.unwrap_err();
+        let err = err.downcast_ref::<UnsupportedByDaemon>().unwrap();
+        assert_eq!(err.required, CREATE_ADDRESS_COUNT_VERSION);
+        assert_eq!(err.actual, (1, 13));
+
+        assert_eq!(wallet.create_address(0, None).await.unwrap().1, 1);
+        assert!(wallet.create_addresses(0, 2, None).await.is_err());
+        assert_eq!(
+            caller
+                .calls()
+                .iter()
+                .map(|(method, _)| *method)
+                .collect::<Vec<_>>(),
+            ["get_version", "create_address"]
+        );
+    }
+
+    #[tokio::test]
+    async fn check_tx_keys_without_batch_support() {
+        let (client, caller) = mock(|_, params| match params["txid"].as_str() {
+            Some(v) if v.starts_with("01") => Ok(json!({
+                "confirmations": 0,
+                "in_pool": true,
+                "received": 1000,
+            })),
+            _ => Err(jsonrpc_core::Error::invalid_params("txid not found")),
+        });
+        caller.rejects_batches.store(true, Ordering::SeqCst);
+        let wallet = client.wallet();
+        let item = |txid: u8| {
+            (
+                CryptoNoteHash([txid; 32]),
+                CryptoNoteHash([3; 32]),
+                address(),
+            )
+        };
+
+        let checked = wallet.check_tx_keys(vec![item(1), item(2)]).await.unwrap();
+        assert_eq!(checked[0].as_ref().unwrap(), &(0, true, 1000));
+        assert_eq!(checked[1].rpc_code(), Some(ErrorCode::InvalidParams.code()));
+        assert_eq!(caller.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn estimate_fee_for_overflow() {
+        let (client, _) = mock(|method, _| match method {
+            "get_version" => Ok(json!({ "version": (1 << 16) | 25 })),
+            _ => Ok(json!({ "size": 1500, "weight": 1500 })),
+        });
+        let wallet = client.wallet();
+        let estimate = |fee: u64| {
+            let (client, _) = mock(move |_, _| {
+                Ok(json!({
+                    "status": "OK",
+                    "fee": fee,
+                    "fees": [],
+                    "quantization_mask": 10000,
+                    "untrusted": false,
+                }))
+            });
+            let daemon = client.daemon();
+            let wallet = wallet.clone();
+            async move {
+                wallet
+                    .estimate_fee_for(2, 2, 16, TransferPriority::Default, &daemon)
+                    .await
+            }
+        };
+
+        assert_eq!(estimate(20).await.unwrap(), 30000);
+        // The product of weight and fee, then its rounding up to the quantization mask.
+        assert!(estimate(u64::MAX / 1000).await.is_err());
+        assert!(estimate(u64::MAX / 1500).await.is_err());
+    }
+}
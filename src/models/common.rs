@@ -0,0 +1,319 @@
+//! Types shared by the daemon and wallet clients.
+
+use crate::util::*;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+macro_rules! hash_type {
+    ($(#[$attr:meta])* $name:ident, $len:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(pub [u8; $len]);
+
+        impl $name {
+            /// Length of the hash in bytes.
+            pub const LEN: usize = $len;
+
+            /// Hash with all bytes set to zero.
+            pub const fn zero() -> Self {
+                Self([0; $len])
+            }
+
+            /// Hash with all bytes set to `byte`.
+            pub const fn repeat_byte(byte: u8) -> Self {
+                Self([byte; $len])
+            }
+
+            /// Length of the hash in bytes, same as [`Self::LEN`].
+            pub const fn len_bytes() -> usize {
+                $len
+            }
+
+            pub fn is_zero(&self) -> bool {
+                self.0.iter().all(|&b| b == 0)
+            }
+
+            /// Copy the hash out of `bytes`.
+            ///
+            /// # Panics
+            /// If `bytes` is not exactly [`Self::LEN`] bytes long.
+            pub fn from_slice(bytes: &[u8]) -> Self {
+                let mut v = Self::zero();
+                v.0.copy_from_slice(bytes);
+                v
+            }
+
+            pub fn as_bytes(&self) -> &[u8] {
+                &self.0
+            }
+
+            pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+
+            pub fn as_fixed_bytes(&self) -> &[u8; $len] {
+                &self.0
+            }
+
+            pub fn as_fixed_bytes_mut(&mut self) -> &mut [u8; $len] {
+                &mut self.0
+            }
+
+            pub fn to_fixed_bytes(self) -> [u8; $len] {
+                self.0
+            }
+
+            /// Hash with `v` in its last 8 bytes, big endian, and zeros before.
+            pub fn from_low_u64_be(v: u64) -> Self {
+                let mut hash = Self::zero();
+                hash.0[$len - 8..].copy_from_slice(&v.to_be_bytes());
+                hash
+            }
+
+            /// Hash with `v` in its first 8 bytes, little endian, and zeros after.
+            pub fn from_low_u64_le(v: u64) -> Self {
+                let mut hash = Self::zero();
+                hash.0[..8].copy_from_slice(&v.to_le_bytes());
+                hash
+            }
+
+            /// Inverse of [`Self::from_low_u64_be`], ignoring all but the last 8 bytes.
+            pub fn to_low_u64_be(&self) -> u64 {
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(&self.0[$len - 8..]);
+                u64::from_be_bytes(bytes)
+            }
+
+            /// Inverse of [`Self::from_low_u64_le`], ignoring all but the first 8 bytes.
+            pub fn to_low_u64_le(&self) -> u64 {
+                let mut bytes = [0; 8];
+                bytes.copy_from_slice(&self.0[..8]);
+                u64::from_le_bytes(bytes)
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(v: [u8; $len]) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$name> for [u8; $len] {
+            fn from(v: $name) -> Self {
+                v.0
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str(&hex::encode(self.0))
+            }
+        }
+
+        impl ::std::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                write!(f, "{}({})", stringify!($name), self)
+            }
+        }
+
+        /// Parses the full hex form in either case, optionally prefixed with `0x`.
+        impl ::std::str::FromStr for $name {
+            type Err = anyhow::Error;
+
+            fn from_str(s: &str) -> anyhow::Result<Self> {
+                let s = s.strip_prefix("0x").unwrap_or(s);
+                let mut v = Self::zero();
+                hex::decode_to_slice(s, &mut v.0)?;
+                Ok(v)
+            }
+        }
+
+        impl HashType for $name {
+            fn bytes(&self) -> &[u8] {
+                &self.0
+            }
+            fn from_str(v: &str) -> anyhow::Result<Self> {
+                v.parse()
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                HashString(*self).serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                HashString::<$name>::deserialize(deserializer).map(|v| v.0)
+            }
+        }
+    };
+}
+
+hash_type!(
+    /// Hash of a block. `Display` prints it as 64 lowercase hex digits, the form used by the RPC interface and block explorers.
+    ///
+    /// Serializes as a hex string as well. Versions of this crate before the switch to hex serialized it as an array of bytes,
+    /// so data stored in that form has to be converted before it can be read back.
+    BlockHash,
+    32
+);
+
+/// Number of atomic units (piconero) in one XMR.
+pub const PICONERO_PER_XMR: u64 = 1_000_000_000_000;
+
+/// Number of blocks a coinbase output stays locked after the block that created it.
+pub const MINED_MONEY_UNLOCK_WINDOW: u64 = 60;
+
+/// Options for [`RpcClient::with_options`](crate::RpcClient::with_options).
+#[derive(Clone, Debug)]
+pub struct RpcClientOptions {
+    /// Network of the node. If set, methods taking addresses reject addresses from other networks.
+    pub network: Option<monero::Network>,
+    /// Value of the `jsonrpc` field of requests, which is omitted if `None`. Defaults to `"2.0"`.
+    pub jsonrpc_version: Option<String>,
+    /// Whether requests carry an `id`. Defaults to `true`. Batched requests always carry one.
+    pub send_id: bool,
+    /// Accept gzip-compressed responses. Requires the `gzip` feature. Large responses such as `get_transfers` on a big wallet shrink considerably,
+    /// at the cost of CPU time on both ends. Only useful if the server or a proxy in front of it compresses responses. Defaults to `false`.
+    #[cfg(feature = "gzip")]
+    pub gzip: bool,
+    /// Accept brotli-compressed responses, which compress better than gzip but are slower to produce. Requires the `brotli` feature. Defaults to `false`.
+    #[cfg(feature = "brotli")]
+    pub brotli: bool,
+    /// Talk HTTP/2 without negotiating it first, which allows multiplexing concurrent calls over one connection.
+    /// Monero's own RPC servers only speak HTTP/1.1, so only enable this behind an HTTP/2 proxy. Over HTTPS, HTTP/2 is negotiated automatically. Defaults to `false`.
+    pub http2_prior_knowledge: bool,
+}
+
+impl Default for RpcClientOptions {
+    fn default() -> Self {
+        Self {
+            network: None,
+            jsonrpc_version: Some("2.0".into()),
+            send_id: true,
+            #[cfg(feature = "gzip")]
+            gzip: false,
+            #[cfg(feature = "brotli")]
+            brotli: false,
+            http2_prior_knowledge: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum Status {
+    OK,
+}
+
+/// Successful daemon response, tagged by its `status` field.
+///
+/// The `credits` and `top_hash` fields of daemons that charge for RPC access are dropped, as RPC payment is not supported.
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "status")]
+pub enum MoneroResult<T> {
+    OK(T),
+}
+
+impl<'de, T> Deserialize<'de> for MoneroResult<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(tag = "status")]
+        enum Tagged<T> {
+            OK(T),
+        }
+
+        let mut value = Value::deserialize(deserializer)?;
+        strip_rpc_payment_fields(&mut value);
+
+        match Tagged::deserialize(value).map_err(D::Error::custom)? {
+            Tagged::OK(v) => Ok(MoneroResult::OK(v)),
+        }
+    }
+}
+
+/// Remove the fields daemons add to responses for RPC payment.
+pub(crate) fn strip_rpc_payment_fields(value: &mut Value) {
+    if let Value::Object(fields) = value {
+        fields.remove("credits");
+        fields.remove("top_hash");
+    }
+}
+
+impl<T> MoneroResult<T> {
+    pub fn into_inner(self) -> T {
+        match self {
+            MoneroResult::OK(v) => v,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+
+    #[test]
+    fn block_hash_display_round_trip() {
+        let hash = HASH.parse::<BlockHash>().unwrap();
+
+        assert_eq!(hash.as_bytes()[0], 1);
+        assert_eq!(hash.to_string(), HASH);
+        assert_eq!(hash.to_string().parse::<BlockHash>().unwrap(), hash);
+        assert_eq!(
+            format!("0x{}", HASH.to_uppercase())
+                .parse::<BlockHash>()
+                .unwrap(),
+            hash
+        );
+        assert!(HASH[2..].parse::<BlockHash>().is_err());
+        assert!(format!("{}00", HASH).parse::<BlockHash>().is_err());
+    }
+
+    #[test]
+    fn block_hash_fixed_hash_api() {
+        assert!(BlockHash::zero().is_zero());
+        assert!(!BlockHash::repeat_byte(1).is_zero());
+        assert_eq!(BlockHash::len_bytes(), 32);
+        assert_eq!(BlockHash::repeat_byte(7).as_fixed_bytes(), &[7; 32]);
+
+        let hash = BlockHash::from_low_u64_be(0x0102);
+        assert_eq!(hash.as_bytes()[30..], [1, 2]);
+        assert_eq!(hash.to_low_u64_be(), 0x0102);
+
+        let mut hash = BlockHash::from_low_u64_le(0x0102);
+        assert_eq!(hash.as_bytes()[..2], [2, 1]);
+        assert_eq!(hash.to_low_u64_le(), 0x0102);
+        hash.as_bytes_mut()[0] = 3;
+        assert_eq!(hash.to_low_u64_le(), 0x0103);
+    }
+
+    #[test]
+    fn block_hash_serde_round_trip() {
+        let hash = HASH.parse::<BlockHash>().unwrap();
+
+        let json = serde_json::to_string(&hash).unwrap();
+        assert_eq!(json, format!("\"{}\"", HASH));
+        assert_eq!(serde_json::from_str::<BlockHash>(&json).unwrap(), hash);
+    }
+}
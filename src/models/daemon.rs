@@ -0,0 +1,1507 @@
+//! Types used by [`DaemonClient`](crate::DaemonClient) and [`RegtestDaemonClient`](crate::RegtestDaemonClient).
+
+use super::{common::*, wallet::TransferPriority};
+use crate::util::*;
+use chrono::prelude::*;
+use monero::{cryptonote::hash::Hash as CryptoNoteHash, Address};
+use serde::{de::IgnoredAny, Deserialize, Serialize};
+use std::{convert::TryFrom, net::Ipv4Addr};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BlockTemplate {
+    pub blockhashing_blob: HashString<Vec<u8>>,
+    pub blocktemplate_blob: HashString<Vec<u8>>,
+    pub difficulty: u64,
+    /// High 64 bits of the difficulty. Zero on daemons that do not report it.
+    #[serde(default)]
+    pub difficulty_top64: u64,
+    /// Full difficulty as a hex string, e.g. `0x4a817c800`. Empty on daemons that do not report it.
+    #[serde(default)]
+    pub wide_difficulty: String,
+    pub expected_reward: u64,
+    pub height: u64,
+    /// Hash of the block seeding the RandomX key of this template.
+    #[serde(default)]
+    pub seed_hash: String,
+    /// Height of the block seeding the RandomX key of this template.
+    #[serde(default)]
+    pub seed_height: u64,
+    /// Seed hash of the next RandomX epoch, empty until it is known.
+    #[serde(default)]
+    pub next_seed_hash: String,
+    pub prev_hash: HashString<BlockHash>,
+    pub reserved_offset: u64,
+    pub untrusted: bool,
+}
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct BlockHeaderResponseR {
+    pub block_size: u64,
+    #[serde(default)]
+    pub block_weight: u64,
+    #[serde(default)]
+    pub cumulative_difficulty: u64,
+    #[serde(default)]
+    pub cumulative_difficulty_top64: u64,
+    pub depth: u64,
+    pub difficulty: u64,
+    pub hash: HashString<BlockHash>,
+    pub height: u64,
+    #[serde(default)]
+    pub long_term_weight: u64,
+    pub major_version: u64,
+    #[serde(default)]
+    pub miner_tx_hash: HashString<CryptoNoteHash>,
+    pub minor_version: u64,
+    pub nonce: u32,
+    pub num_txes: u64,
+    pub orphan_status: bool,
+    pub prev_hash: HashString<BlockHash>,
+    pub reward: u64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    #[serde(default, rename = "difficulty_top64")]
+    _difficulty_top64: IgnoredAny,
+    #[serde(default, rename = "pow_hash")]
+    _pow_hash: IgnoredAny,
+    #[serde(default, rename = "wide_cumulative_difficulty")]
+    _wide_cumulative_difficulty: IgnoredAny,
+    #[serde(default, rename = "wide_difficulty")]
+    _wide_difficulty: IgnoredAny,
+}
+
+impl From<BlockHeaderResponseR> for BlockHeaderResponse {
+    fn from(value: BlockHeaderResponseR) -> Self {
+        Self {
+            block_size: value.block_size,
+            block_weight: value.block_weight,
+            cumulative_difficulty: wide_u128(
+                value.cumulative_difficulty,
+                value.cumulative_difficulty_top64,
+            ),
+            depth: value.depth,
+            difficulty: value.difficulty,
+            hash: value.hash.0,
+            height: value.height,
+            long_term_weight: value.long_term_weight,
+            major_version: value.major_version,
+            miner_tx_hash: value.miner_tx_hash.0,
+            minor_version: value.minor_version,
+            nonce: value.nonce,
+            num_txes: value.num_txes,
+            orphan_status: value.orphan_status,
+            prev_hash: value.prev_hash.0,
+            reward: value.reward,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BlockHeaderResponse {
+    pub block_size: u64,
+    /// Weight of the block, used for fees and the block size limit. Zero on daemons that do not report it.
+    pub block_weight: u64,
+    /// Cumulative difficulty of the chain up to this block. Zero on daemons that do not report it.
+    pub cumulative_difficulty: u128,
+    pub depth: u64,
+    pub difficulty: u64,
+    pub hash: BlockHash,
+    pub height: u64,
+    /// Long term weight of the block, used for the median block weight. Zero on daemons that do not report it.
+    pub long_term_weight: u64,
+    pub major_version: u64,
+    /// Hash of the coinbase transaction. Zero on daemons that do not report it.
+    pub miner_tx_hash: CryptoNoteHash,
+    pub minor_version: u64,
+    pub nonce: u32,
+    pub num_txes: u64,
+    pub orphan_status: bool,
+    pub prev_hash: BlockHash,
+    pub reward: u64,
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct GetBlockResponseR {
+    pub blob: HashString<Vec<u8>>,
+    pub block_header: BlockHeaderResponseR,
+    pub json: String,
+    pub miner_tx_hash: HashString<CryptoNoteHash>,
+    pub untrusted: bool,
+    #[serde(default, rename = "tx_hashes")]
+    _tx_hashes: IgnoredAny,
+}
+
+impl TryFrom<GetBlockResponseR> for GetBlockResponse {
+    type Error = anyhow::Error;
+
+    fn try_from(value: GetBlockResponseR) -> anyhow::Result<Self> {
+        Ok(Self {
+            blob: value.blob.0,
+            block_header: value.block_header.into(),
+            json: serde_json::from_str(&value.json)?,
+            miner_tx_hash: value.miner_tx_hash.0,
+            untrusted: value.untrusted,
+        })
+    }
+}
+
+/// A full block, see [`DaemonClient::get_block`](crate::DaemonClient::get_block).
+#[derive(Clone, Debug)]
+pub struct GetBlockResponse {
+    /// Serialized block.
+    pub blob: Vec<u8>,
+    pub block_header: BlockHeaderResponse,
+    /// Parsed block.
+    pub json: BlockJson,
+    pub miner_tx_hash: CryptoNoteHash,
+    pub untrusted: bool,
+}
+
+/// Block in the daemon's JSON representation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BlockJson {
+    pub major_version: u64,
+    pub minor_version: u64,
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    pub prev_id: HashString<BlockHash>,
+    pub nonce: u32,
+    /// Coinbase transaction.
+    pub miner_tx: TransactionJson,
+    /// Hashes of the block's transactions, not including the coinbase transaction.
+    pub tx_hashes: Vec<HashString<CryptoNoteHash>>,
+}
+
+/// A full block along with its transactions, see [`DaemonClient::get_block_with_txs`](crate::DaemonClient::get_block_with_txs).
+#[derive(Clone, Debug)]
+pub struct BlockWithTxs {
+    pub block: GetBlockResponse,
+    /// Transactions in the order of `block.json.tx_hashes`, not including the coinbase transaction.
+    pub txs: Vec<TransactionEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct FeeEstimate {
+    /// Fee per byte of transaction weight, in piconero.
+    pub fee: u64,
+    /// Fees per byte for each priority tier. Only returned by newer daemons.
+    #[serde(default)]
+    pub fees: Vec<u64>,
+    /// The final fee must be rounded up to a multiple of this value.
+    pub quantization_mask: u64,
+    #[serde(default)]
+    pub untrusted: bool,
+}
+
+impl FeeEstimate {
+    /// Fee per byte for a priority tier, falling back to `fee` on daemons that do not return per-tier fees.
+    /// Like wallet2, priority `n` uses tier `max(n, 1) - 1`.
+    pub fn for_priority(&self, priority: TransferPriority) -> u64 {
+        let tier = match priority {
+            TransferPriority::Default | TransferPriority::Unimportant => 0,
+            TransferPriority::Elevated => 1,
+            TransferPriority::Priority => 2,
+        };
+
+        self.fees.get(tier).copied().unwrap_or(self.fee)
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct DaemonInfoR {
+    pub height: u64,
+    pub target_height: u64,
+    pub difficulty: u64,
+    #[serde(default)]
+    pub difficulty_top64: u64,
+    #[serde(default)]
+    pub cumulative_difficulty: u64,
+    #[serde(default)]
+    pub cumulative_difficulty_top64: u64,
+    #[serde(default)]
+    pub target: u64,
+    #[serde(default)]
+    pub top_block_hash: HashString<BlockHash>,
+    #[serde(default)]
+    pub block_weight_limit: u64,
+    #[serde(default)]
+    pub block_weight_median: u64,
+    #[serde(default)]
+    pub version: String,
+    #[serde(default)]
+    pub offline: bool,
+    #[serde(default)]
+    pub busy_syncing: bool,
+    #[serde(default)]
+    pub restricted: bool,
+    #[serde(default)]
+    pub update_available: bool,
+    #[serde(default)]
+    pub untrusted: bool,
+    pub tx_count: u64,
+    pub tx_pool_size: u64,
+    pub alt_blocks_count: u64,
+    pub outgoing_connections_count: u64,
+    pub incoming_connections_count: u64,
+    pub synchronized: bool,
+    pub mainnet: bool,
+    pub testnet: bool,
+    pub stagenet: bool,
+    #[serde(default)]
+    pub nettype: String,
+    #[serde(default)]
+    pub database_size: u64,
+    #[serde(default, rename = "adjusted_time")]
+    _adjusted_time: IgnoredAny,
+    #[serde(default, rename = "block_size_limit")]
+    _block_size_limit: IgnoredAny,
+    #[serde(default, rename = "block_size_median")]
+    _block_size_median: IgnoredAny,
+    #[serde(default, rename = "bootstrap_daemon_address")]
+    _bootstrap_daemon_address: IgnoredAny,
+    #[serde(default, rename = "free_space")]
+    _free_space: IgnoredAny,
+    #[serde(default, rename = "grey_peerlist_size")]
+    _grey_peerlist_size: IgnoredAny,
+    #[serde(default, rename = "height_without_bootstrap")]
+    _height_without_bootstrap: IgnoredAny,
+    #[serde(default, rename = "rpc_connections_count")]
+    _rpc_connections_count: IgnoredAny,
+    #[serde(default, rename = "start_time")]
+    _start_time: IgnoredAny,
+    #[serde(default, rename = "was_bootstrap_ever_used")]
+    _was_bootstrap_ever_used: IgnoredAny,
+    #[serde(default, rename = "white_peerlist_size")]
+    _white_peerlist_size: IgnoredAny,
+    #[serde(default, rename = "wide_cumulative_difficulty")]
+    _wide_cumulative_difficulty: IgnoredAny,
+    #[serde(default, rename = "wide_difficulty")]
+    _wide_difficulty: IgnoredAny,
+}
+
+impl From<DaemonInfoR> for DaemonInfo {
+    fn from(value: DaemonInfoR) -> Self {
+        Self {
+            height: value.height,
+            target_height: value.target_height,
+            difficulty: wide_u128(value.difficulty, value.difficulty_top64),
+            cumulative_difficulty: wide_u128(
+                value.cumulative_difficulty,
+                value.cumulative_difficulty_top64,
+            ),
+            target: value.target,
+            top_block_hash: value.top_block_hash.0,
+            block_weight_limit: value.block_weight_limit,
+            block_weight_median: value.block_weight_median,
+            version: value.version,
+            offline: value.offline,
+            busy_syncing: value.busy_syncing,
+            restricted: value.restricted,
+            update_available: value.update_available,
+            untrusted: value.untrusted,
+            tx_count: value.tx_count,
+            tx_pool_size: value.tx_pool_size,
+            alt_blocks_count: value.alt_blocks_count,
+            outgoing_connections_count: value.outgoing_connections_count,
+            incoming_connections_count: value.incoming_connections_count,
+            synchronized: value.synchronized,
+            mainnet: value.mainnet,
+            testnet: value.testnet,
+            stagenet: value.stagenet,
+            nettype: value.nettype,
+            database_size: value.database_size,
+        }
+    }
+}
+
+/// State of the node and the network, see [`DaemonClient::get_info`](crate::DaemonClient::get_info).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DaemonInfo {
+    /// Current length of the longest chain known to the daemon.
+    pub height: u64,
+    /// Height the daemon is syncing to, 0 if it is not syncing.
+    pub target_height: u64,
+    /// Network difficulty of the next block.
+    pub difficulty: u128,
+    /// Cumulative difficulty of the chain. Zero on daemons that do not report it.
+    pub cumulative_difficulty: u128,
+    /// Target time between blocks, in seconds.
+    pub target: u64,
+    /// Hash of the top block of the chain.
+    pub top_block_hash: BlockHash,
+    /// Maximum weight of the next block.
+    pub block_weight_limit: u64,
+    /// Median weight of the last blocks, the base of the fee and block size calculations.
+    pub block_weight_median: u64,
+    /// Version of the daemon software, e.g. `0.18.3.1-release`. Empty on restricted RPC servers.
+    pub version: String,
+    /// Whether the daemon runs without network access.
+    pub offline: bool,
+    /// Whether the daemon is busy downloading blocks.
+    pub busy_syncing: bool,
+    /// Whether this is a restricted RPC server, which hides some of the node's details.
+    pub restricted: bool,
+    /// Whether a newer daemon version is available.
+    pub update_available: bool,
+    /// Whether the response comes from a bootstrap daemon, whose data cannot be trusted.
+    pub untrusted: bool,
+    /// Number of transactions in the blockchain.
+    pub tx_count: u64,
+    /// Number of transactions in the pool.
+    pub tx_pool_size: u64,
+    pub alt_blocks_count: u64,
+    pub outgoing_connections_count: u64,
+    pub incoming_connections_count: u64,
+    pub synchronized: bool,
+    pub mainnet: bool,
+    pub testnet: bool,
+    pub stagenet: bool,
+    /// Network name, e.g. `mainnet` or `fakechain` for regtest. Empty on older daemons.
+    pub nettype: String,
+    /// Size of the blockchain database in bytes. Rounded on restricted RPC servers.
+    pub database_size: u64,
+}
+
+impl DaemonInfo {
+    /// Whether the daemon considers itself synchronized and has caught up with its target height.
+    pub fn is_synced(&self) -> bool {
+        self.synchronized && self.height >= self.target_height
+    }
+
+    /// Network the daemon runs on. Regtest daemons report no network flag and use mainnet addresses.
+    pub fn network(&self) -> monero::Network {
+        if self.testnet {
+            monero::Network::Testnet
+        } else if self.stagenet {
+            monero::Network::Stagenet
+        } else {
+            monero::Network::Mainnet
+        }
+    }
+
+    /// Synchronization progress, from 0.0 to 1.0.
+    pub fn sync_progress(&self) -> f64 {
+        if self.target_height == 0 || self.height >= self.target_height {
+            1.0
+        } else {
+            self.height as f64 / self.target_height as f64
+        }
+    }
+}
+
+/// (De)serialize a `u64` from the hex string the daemon uses for peer IDs.
+mod hex_u64 {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &u64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("{:016x}", value))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        if s.is_empty() {
+            return Ok(0);
+        }
+
+        u64::from_str_radix(&s, 16).map_err(D::Error::custom)
+    }
+}
+
+/// P2P connection of the daemon, see [`DaemonClient::get_connections`](crate::DaemonClient::get_connections).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ConnectionInfo {
+    /// Remote address, as `host:port`.
+    pub address: String,
+    #[serde(with = "hex_u64")]
+    pub peer_id: u64,
+    /// Whether the remote peer opened the connection.
+    pub incoming: bool,
+    /// Height of the peer's chain.
+    pub height: u64,
+    /// Seconds since the connection was established.
+    pub live_time: u64,
+    /// Bytes received.
+    pub recv_count: u64,
+    /// Bytes sent.
+    pub send_count: u64,
+    /// Connection state, e.g. `normal` or `synchronizing`.
+    pub state: String,
+    pub connection_id: String,
+    /// Kind of address: 1 for IPv4, 2 for IPv6, 3 for Tor and 4 for I2P.
+    #[serde(default)]
+    pub address_type: u8,
+    #[serde(default)]
+    pub host: String,
+    #[serde(default)]
+    pub ip: String,
+    #[serde(default)]
+    pub port: String,
+    /// Whether the peer is on the local network.
+    #[serde(default)]
+    pub local_ip: bool,
+    /// Whether the peer is on the same machine.
+    #[serde(default)]
+    pub localhost: bool,
+    /// Port of the peer's restricted RPC server, 0 if it does not advertise one.
+    #[serde(default)]
+    pub rpc_port: u16,
+    #[serde(default)]
+    pub rpc_credits_per_hash: u32,
+    #[serde(default)]
+    pub ssl: bool,
+    #[serde(default)]
+    pub support_flags: u32,
+    /// Pruning seed of the peer's chain, 0 if it is not pruned.
+    #[serde(default)]
+    pub pruning_seed: u32,
+    /// Seconds since data was last received.
+    #[serde(default)]
+    pub recv_idle_time: u64,
+    /// Seconds since data was last sent.
+    #[serde(default)]
+    pub send_idle_time: u64,
+    /// Average download speed, in kB/s.
+    #[serde(default)]
+    pub avg_download: u64,
+    /// Average upload speed, in kB/s.
+    #[serde(default)]
+    pub avg_upload: u64,
+    /// Current download speed, in kB/s.
+    #[serde(default)]
+    pub current_download: u64,
+    /// Current upload speed, in kB/s.
+    #[serde(default)]
+    pub current_upload: u64,
+}
+
+/// Range of blocks being downloaded from a peer.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SyncSpan {
+    pub connection_id: String,
+    pub nblocks: u64,
+    pub rate: u64,
+    pub remote_address: String,
+    pub size: u64,
+    pub speed: u64,
+    pub start_block_height: u64,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct SyncPeerR {
+    pub info: ConnectionInfo,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct SyncInfoR {
+    pub height: u64,
+    pub target_height: u64,
+    pub next_needed_pruning_seed: u32,
+    #[serde(default)]
+    pub peers: Vec<SyncPeerR>,
+    #[serde(default)]
+    pub spans: Vec<SyncSpan>,
+    #[serde(default, rename = "overview")]
+    _overview: IgnoredAny,
+    #[serde(default, rename = "untrusted")]
+    _untrusted: IgnoredAny,
+}
+
+impl From<SyncInfoR> for SyncInfo {
+    fn from(value: SyncInfoR) -> Self {
+        let spans = value.spans;
+
+        Self {
+            height: value.height,
+            target_height: value.target_height,
+            next_needed_pruning_seed: value.next_needed_pruning_seed,
+            peers: value
+                .peers
+                .into_iter()
+                .map(|peer| SyncPeer {
+                    spans: spans
+                        .iter()
+                        .filter(|span| span.connection_id == peer.info.connection_id)
+                        .cloned()
+                        .collect(),
+                    info: peer.info,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Peer the daemon syncs with, along with the block spans it is downloading from it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SyncPeer {
+    pub info: ConnectionInfo,
+    /// Empty when nothing is being downloaded from this peer.
+    pub spans: Vec<SyncSpan>,
+}
+
+/// Synchronization state of the daemon, see [`DaemonClient::sync_info`](crate::DaemonClient::sync_info).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SyncInfo {
+    pub height: u64,
+    /// Height the daemon is syncing to, 0 if it is not syncing.
+    pub target_height: u64,
+    pub next_needed_pruning_seed: u32,
+    pub peers: Vec<SyncPeer>,
+}
+
+/// Everything about a node in one struct, see [`DaemonClient::node_status`](crate::DaemonClient::node_status).
+#[derive(Clone, Debug)]
+pub struct NodeStatus {
+    pub info: DaemonInfo,
+    pub sync: SyncInfo,
+    pub connections: Vec<ConnectionInfo>,
+}
+
+/// Build a 128-bit value from the low and high halves the daemon sends separately.
+fn wide_u128(low: u64, top64: u64) -> u128 {
+    (u128::from(top64) << 64) | u128::from(low)
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct CoinbaseTxSumR {
+    pub emission_amount: u64,
+    #[serde(default)]
+    pub emission_amount_top64: u64,
+    pub fee_amount: u64,
+    #[serde(default)]
+    pub fee_amount_top64: u64,
+    #[serde(default, rename = "untrusted")]
+    _untrusted: IgnoredAny,
+    #[serde(default, rename = "wide_emission_amount")]
+    _wide_emission_amount: IgnoredAny,
+    #[serde(default, rename = "wide_fee_amount")]
+    _wide_fee_amount: IgnoredAny,
+}
+
+impl From<CoinbaseTxSumR> for CoinbaseTxSum {
+    fn from(value: CoinbaseTxSumR) -> Self {
+        Self {
+            emission_amount: wide_u128(value.emission_amount, value.emission_amount_top64),
+            fee_amount: wide_u128(value.fee_amount, value.fee_amount_top64),
+        }
+    }
+}
+
+/// Coins created and fees paid over a range of blocks, see [`DaemonClient::get_coinbase_tx_sum`](crate::DaemonClient::get_coinbase_tx_sum).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CoinbaseTxSum {
+    /// Newly created coins, in piconero.
+    pub emission_amount: u128,
+    /// Fees paid to miners, in piconero.
+    pub fee_amount: u128,
+}
+
+/// Outcome of [`DaemonClient::send_raw_transaction`](crate::DaemonClient::send_raw_transaction).
+/// A rejected transaction is not an error: check `is_accepted` and the flags for the reason.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SendRawTransactionResult {
+    /// `OK` if the transaction was accepted, `Failed` otherwise.
+    pub status: String,
+    /// Human-readable reason of a rejection. May be empty.
+    #[serde(default)]
+    pub reason: String,
+    /// The transaction was accepted but not relayed, as requested by `do_not_relay`.
+    #[serde(default)]
+    pub not_relayed: bool,
+    /// Ring size too small.
+    #[serde(default)]
+    pub low_mixin: bool,
+    /// A key image was already spent.
+    #[serde(default)]
+    pub double_spend: bool,
+    #[serde(default)]
+    pub invalid_input: bool,
+    #[serde(default)]
+    pub invalid_output: bool,
+    #[serde(default)]
+    pub too_big: bool,
+    /// Outputs are worth more than inputs.
+    #[serde(default)]
+    pub overspend: bool,
+    #[serde(default)]
+    pub fee_too_low: bool,
+    #[serde(default)]
+    pub too_few_outputs: bool,
+    #[serde(default)]
+    pub sanity_check_failed: bool,
+    #[serde(default)]
+    pub tx_extra_too_big: bool,
+    /// The transaction has an unlock time, which daemons no longer relay.
+    #[serde(default)]
+    pub nonzero_unlock_time: bool,
+    #[serde(default)]
+    pub untrusted: bool,
+}
+
+impl SendRawTransactionResult {
+    pub fn is_accepted(&self) -> bool {
+        self.status == "OK"
+    }
+}
+
+/// Spent status of a key image, see [`DaemonClient::is_key_image_spent`](crate::DaemonClient::is_key_image_spent).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyImageSpentStatus {
+    Unspent,
+    SpentInBlockchain,
+    SpentInPool,
+}
+
+impl TryFrom<u8> for KeyImageSpentStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> anyhow::Result<Self> {
+        match value {
+            0 => Ok(KeyImageSpentStatus::Unspent),
+            1 => Ok(KeyImageSpentStatus::SpentInBlockchain),
+            2 => Ok(KeyImageSpentStatus::SpentInPool),
+            other => Err(anyhow::anyhow!("Unknown key image spent status {}", other)),
+        }
+    }
+}
+
+/// Output to look up with [`DaemonClient::get_outs`](crate::DaemonClient::get_outs).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GetOutsEntry {
+    /// Amount of the output, 0 for RingCT outputs.
+    pub amount: u64,
+    /// Global index of the output among the outputs of this amount.
+    pub index: u64,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct OutKeyR {
+    pub key: HashString<CryptoNoteHash>,
+    pub mask: HashString<CryptoNoteHash>,
+    pub unlocked: bool,
+    pub height: u64,
+    #[serde(default)]
+    pub txid: String,
+}
+
+impl TryFrom<OutKeyR> for OutKey {
+    type Error = anyhow::Error;
+
+    fn try_from(value: OutKeyR) -> anyhow::Result<Self> {
+        Ok(Self {
+            key: value.key.0.to_bytes(),
+            mask: value.mask.0.to_bytes(),
+            unlocked: value.unlocked,
+            height: value.height,
+            txid: if value.txid.is_empty() {
+                None
+            } else {
+                Some(value.txid.parse::<HashString<CryptoNoteHash>>()?.0)
+            },
+        })
+    }
+}
+
+/// Output returned by [`DaemonClient::get_outs`](crate::DaemonClient::get_outs).
+#[derive(Clone, Debug)]
+pub struct OutKey {
+    /// One-time public key of the output.
+    pub key: [u8; 32],
+    /// Commitment to the amount of the output.
+    pub mask: [u8; 32],
+    pub unlocked: bool,
+    /// Height of the block containing the output.
+    pub height: u64,
+    /// Transaction that created the output, if requested.
+    pub txid: Option<CryptoNoteHash>,
+}
+
+/// Hard fork state of the daemon, as reported by [`DaemonClient::hard_fork_info`](crate::DaemonClient::hard_fork_info).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(into = "u8", try_from = "u8")]
+pub enum HardForkState {
+    /// The network has likely forked to a version the daemon does not know about.
+    LikelyForked,
+    /// An update is needed to follow the next fork.
+    UpdateNeeded,
+    /// The daemon is ready for the known forks.
+    Ready,
+}
+
+impl From<HardForkState> for u8 {
+    fn from(value: HardForkState) -> Self {
+        match value {
+            HardForkState::LikelyForked => 0,
+            HardForkState::UpdateNeeded => 1,
+            HardForkState::Ready => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for HardForkState {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> anyhow::Result<Self> {
+        match value {
+            0 => Ok(HardForkState::LikelyForked),
+            1 => Ok(HardForkState::UpdateNeeded),
+            2 => Ok(HardForkState::Ready),
+            other => Err(anyhow::anyhow!("Unknown hard fork state {}", other)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct HardForkInfo {
+    /// Current consensus version.
+    pub version: u8,
+    /// Whether `version` is enabled.
+    pub enabled: bool,
+    /// Height at which `version` activated.
+    pub earliest_height: u64,
+    /// Number of votes needed to enable the next version.
+    pub threshold: u64,
+    /// Number of blocks in the voting window voting for `voting`.
+    pub votes: u64,
+    /// Version voted for by the blocks.
+    pub voting: u8,
+    /// Number of blocks of the voting window.
+    pub window: u64,
+    pub state: HardForkState,
+    pub untrusted: bool,
+}
+
+/// Ban or unban of a peer, see [`DaemonClient::set_bans`](crate::DaemonClient::set_bans). Exactly one of `host` and `ip` must be set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ban {
+    /// Host name or dotted IP address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+    /// IPv4 address in the daemon's representation, see `Ban::ipv4`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<u32>,
+    /// `true` to ban, `false` to lift a ban.
+    pub ban: bool,
+    /// Duration of the ban.
+    pub seconds: u64,
+}
+
+impl Ban {
+    pub fn host(host: String, ban: bool, seconds: u64) -> Self {
+        Self {
+            host: Some(host),
+            ip: None,
+            ban,
+            seconds,
+        }
+    }
+
+    /// Ban an IPv4 address. The daemon expects the address octets in memory order, read as a little-endian `u32`.
+    pub fn ipv4(ip: Ipv4Addr, ban: bool, seconds: u64) -> Self {
+        Self {
+            host: None,
+            ip: Some(u32::from_le_bytes(ip.octets())),
+            ban,
+            seconds,
+        }
+    }
+}
+
+/// Banned peer, see [`DaemonClient::get_bans`](crate::DaemonClient::get_bans).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BannedHost {
+    pub host: String,
+    /// IPv4 address in the daemon's representation, 0 for other hosts. See `BannedHost::ipv4`.
+    pub ip: u32,
+    /// Seconds until the ban is lifted.
+    pub seconds: u64,
+}
+
+impl BannedHost {
+    /// IPv4 address of the peer, if it has one.
+    pub fn ipv4(&self) -> Option<Ipv4Addr> {
+        Some(self.ip)
+            .filter(|ip| *ip != 0)
+            .map(|ip| Ipv4Addr::from(ip.to_le_bytes()))
+    }
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct AlternateChainR {
+    pub block_hash: HashString<BlockHash>,
+    pub height: u64,
+    pub length: u64,
+    pub difficulty: u64,
+    #[serde(default)]
+    pub difficulty_top64: u64,
+    #[serde(default)]
+    pub block_hashes: Vec<HashString<BlockHash>>,
+    pub main_chain_parent_block: HashString<BlockHash>,
+    #[serde(default, rename = "wide_difficulty")]
+    _wide_difficulty: IgnoredAny,
+}
+
+impl From<AlternateChainR> for AlternateChain {
+    fn from(value: AlternateChainR) -> Self {
+        Self {
+            block_hash: value.block_hash.0,
+            height: value.height,
+            length: value.length,
+            difficulty: wide_u128(value.difficulty, value.difficulty_top64),
+            block_hashes: value.block_hashes.into_iter().map(|v| v.0).collect(),
+            main_chain_parent_block: value.main_chain_parent_block.0,
+        }
+    }
+}
+
+/// Chain competing with the main chain, see [`DaemonClient::get_alternate_chains`](crate::DaemonClient::get_alternate_chains).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AlternateChain {
+    /// Hash of the top block of the chain.
+    pub block_hash: BlockHash,
+    /// Height of the top block of the chain.
+    pub height: u64,
+    /// Number of blocks of the chain since it forked from the main chain.
+    pub length: u64,
+    /// Cumulative difficulty of the chain.
+    pub difficulty: u128,
+    /// Hashes of the blocks of the chain, from the top.
+    pub block_hashes: Vec<BlockHash>,
+    /// Block of the main chain the chain forked from.
+    pub main_chain_parent_block: BlockHash,
+}
+
+/// Transaction waiting in the pool, see [`DaemonClient::get_txpool_backlog`](crate::DaemonClient::get_txpool_backlog).
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TxBacklogEntry {
+    pub weight: u64,
+    pub fee: u64,
+    /// Seconds since the transaction entered the pool.
+    pub time_in_pool: u64,
+}
+
+/// Distribution of the outputs of an amount over blocks, see [`DaemonClient::get_output_distribution`](crate::DaemonClient::get_output_distribution).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct OutputDistribution {
+    pub amount: u64,
+    /// Height of the first block of `distribution`.
+    pub start_height: u64,
+    /// Number of outputs created before `start_height`. Only set for cumulative distributions.
+    pub base: u64,
+    /// Number of outputs per block from `start_height`, or running totals for cumulative distributions.
+    pub distribution: Vec<u64>,
+    #[serde(default)]
+    pub binary: bool,
+    #[serde(default, rename = "compress")]
+    pub compressed: bool,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct MiningStatusR {
+    pub active: bool,
+    pub speed: u64,
+    pub threads_count: u64,
+    pub address: String,
+    pub is_background_mining_enabled: bool,
+    #[serde(default)]
+    pub block_reward: u64,
+    #[serde(default, rename = "bg_idle_threshold")]
+    _bg_idle_threshold: IgnoredAny,
+    #[serde(default, rename = "bg_ignore_battery")]
+    _bg_ignore_battery: IgnoredAny,
+    #[serde(default, rename = "bg_min_idle_seconds")]
+    _bg_min_idle_seconds: IgnoredAny,
+    #[serde(default, rename = "bg_target")]
+    _bg_target: IgnoredAny,
+    #[serde(default, rename = "block_target")]
+    _block_target: IgnoredAny,
+    #[serde(default, rename = "difficulty")]
+    _difficulty: IgnoredAny,
+    #[serde(default, rename = "difficulty_top64")]
+    _difficulty_top64: IgnoredAny,
+    #[serde(default, rename = "pow_algorithm")]
+    _pow_algorithm: IgnoredAny,
+    #[serde(default, rename = "untrusted")]
+    _untrusted: IgnoredAny,
+    #[serde(default, rename = "wide_difficulty")]
+    _wide_difficulty: IgnoredAny,
+}
+
+impl TryFrom<MiningStatusR> for MiningStatus {
+    type Error = anyhow::Error;
+
+    fn try_from(value: MiningStatusR) -> anyhow::Result<Self> {
+        Ok(Self {
+            active: value.active,
+            speed: value.speed,
+            threads_count: value.threads_count,
+            address: if value.address.is_empty() {
+                None
+            } else {
+                Some(value.address.parse()?)
+            },
+            is_background_mining_enabled: value.is_background_mining_enabled,
+            block_reward: value.block_reward,
+        })
+    }
+}
+
+/// State of the daemon's miner, see [`DaemonClient::mining_status`](crate::DaemonClient::mining_status).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct MiningStatus {
+    pub active: bool,
+    /// Hash rate in hashes per second.
+    pub speed: u64,
+    pub threads_count: u64,
+    /// Address receiving the rewards, `None` when not mining.
+    pub address: Option<Address>,
+    pub is_background_mining_enabled: bool,
+    /// Reward of the block being mined, in piconero.
+    pub block_reward: u64,
+}
+
+/// Peer known to the daemon, see [`DaemonClient::get_peer_list`](crate::DaemonClient::get_peer_list).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Peer {
+    pub id: u64,
+    pub host: String,
+    /// IPv4 address in the daemon's representation, see `BannedHost::ipv4`.
+    pub ip: u32,
+    pub port: u16,
+    /// Port of the peer's restricted RPC server, 0 if it does not advertise one.
+    #[serde(default)]
+    pub rpc_port: u16,
+    #[serde(default)]
+    pub rpc_credits_per_hash: u32,
+    /// When the peer was last seen.
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub last_seen: DateTime<Utc>,
+    /// Pruning seed of the peer's chain, 0 if it is not pruned.
+    #[serde(default)]
+    pub pruning_seed: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct PeerLists {
+    /// Peers the daemon connected to.
+    #[serde(default)]
+    pub white_list: Vec<Peer>,
+    /// Peers the daemon heard of but did not connect to.
+    #[serde(default)]
+    pub gray_list: Vec<Peer>,
+    #[serde(default)]
+    pub untrusted: bool,
+}
+
+/// Network traffic of the daemon since it started, see [`DaemonClient::get_net_stats`](crate::DaemonClient::get_net_stats).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct NetStats {
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub start_time: DateTime<Utc>,
+    pub total_packets_in: u64,
+    pub total_bytes_in: u64,
+    pub total_packets_out: u64,
+    pub total_bytes_out: u64,
+    #[serde(default)]
+    pub untrusted: bool,
+}
+
+/// RPC version of the daemon, see [`DaemonClient::get_version`](crate::DaemonClient::get_version).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct DaemonVersion {
+    pub major: u16,
+    pub minor: u16,
+    /// Whether the daemon is a release build.
+    pub release: bool,
+}
+
+/// Aggregate statistics of the transaction pool, see [`DaemonClient::get_tx_pool_stats`](crate::DaemonClient::get_tx_pool_stats).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TxPoolStats {
+    /// Total size of the transactions in bytes.
+    pub bytes_total: u64,
+    pub bytes_min: u64,
+    pub bytes_max: u64,
+    pub bytes_med: u64,
+    /// Sum of the fees, in piconero.
+    pub fee_total: u64,
+    /// Timestamp of the oldest transaction.
+    pub oldest: u64,
+    pub txs_total: u64,
+    /// Number of transactions that failed to be added to a block.
+    pub num_failing: u64,
+    /// Number of transactions older than 10 minutes.
+    pub num_10m: u64,
+    pub num_not_relayed: u64,
+    /// Age under which 98% of the transactions fall, in seconds.
+    pub histo_98pc: u64,
+    /// Transactions by age, in equal time slices from the newest to `histo_98pc`, or to the oldest transaction if there are few.
+    #[serde(default)]
+    pub histo: Vec<TxPoolHistoBin>,
+    pub num_double_spends: u64,
+}
+
+/// Slice of [`TxPoolStats::histo`].
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TxPoolHistoBin {
+    pub txs: u32,
+    /// Total size of the transactions in bytes.
+    pub bytes: u64,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct PoolTransactionR {
+    pub id_hash: HashString<CryptoNoteHash>,
+    pub tx_json: String,
+    pub blob_size: u64,
+    #[serde(default)]
+    pub weight: Option<u64>,
+    pub fee: u64,
+    pub receive_time: i64,
+    pub relayed: bool,
+    pub do_not_relay: bool,
+    pub double_spend_seen: bool,
+    #[serde(default, rename = "kept_by_block")]
+    _kept_by_block: IgnoredAny,
+    #[serde(default, rename = "last_failed_height")]
+    _last_failed_height: IgnoredAny,
+    #[serde(default, rename = "last_failed_id_hash")]
+    _last_failed_id_hash: IgnoredAny,
+    #[serde(default, rename = "last_relayed_time")]
+    _last_relayed_time: IgnoredAny,
+    #[serde(default, rename = "max_used_block_height")]
+    _max_used_block_height: IgnoredAny,
+    #[serde(default, rename = "max_used_block_id_hash")]
+    _max_used_block_id_hash: IgnoredAny,
+    #[serde(default, rename = "tx_blob")]
+    _tx_blob: IgnoredAny,
+}
+
+impl TryFrom<PoolTransactionR> for PoolTransaction {
+    type Error = anyhow::Error;
+
+    fn try_from(value: PoolTransactionR) -> anyhow::Result<Self> {
+        Ok(Self {
+            id_hash: value.id_hash.0,
+            tx_json: serde_json::from_str(&value.tx_json)?,
+            blob_size: value.blob_size,
+            weight: value.weight.unwrap_or(value.blob_size),
+            fee: value.fee,
+            receive_time: Utc
+                .timestamp_opt(value.receive_time, 0)
+                .single()
+                .ok_or_else(|| anyhow::anyhow!("Invalid receive_time {}", value.receive_time))?,
+            relayed: value.relayed,
+            do_not_relay: value.do_not_relay,
+            double_spend_seen: value.double_spend_seen,
+        })
+    }
+}
+
+/// Transaction in the pool, see [`DaemonClient::get_transaction_pool`](crate::DaemonClient::get_transaction_pool).
+#[derive(Clone, Debug)]
+pub struct PoolTransaction {
+    pub id_hash: CryptoNoteHash,
+    pub tx_json: TransactionJson,
+    pub blob_size: u64,
+    /// Weight of the transaction. Same as `blob_size` on older daemons.
+    pub weight: u64,
+    pub fee: u64,
+    /// When the transaction entered the pool.
+    pub receive_time: DateTime<Utc>,
+    pub relayed: bool,
+    pub do_not_relay: bool,
+    pub double_spend_seen: bool,
+}
+
+/// Key image spent by transactions in the pool.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SpentKeyImage {
+    /// The key image.
+    pub id_hash: HashString<Vec<u8>>,
+    /// Transactions spending it. More than one means a double spend attempt.
+    pub txs_hashes: Vec<HashString<CryptoNoteHash>>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct TxPoolR {
+    #[serde(default)]
+    pub transactions: Vec<PoolTransactionR>,
+    #[serde(default)]
+    pub spent_key_images: Vec<SpentKeyImage>,
+    #[serde(default, rename = "untrusted")]
+    _untrusted: IgnoredAny,
+}
+
+impl TryFrom<TxPoolR> for TxPool {
+    type Error = anyhow::Error;
+
+    fn try_from(value: TxPoolR) -> anyhow::Result<Self> {
+        Ok(Self {
+            transactions: value
+                .transactions
+                .into_iter()
+                .map(TryFrom::try_from)
+                .collect::<anyhow::Result<_>>()?,
+            spent_key_images: value.spent_key_images,
+        })
+    }
+}
+
+/// Content of the transaction pool, see [`DaemonClient::get_transaction_pool`](crate::DaemonClient::get_transaction_pool).
+#[derive(Clone, Debug)]
+pub struct TxPool {
+    pub transactions: Vec<PoolTransaction>,
+    pub spent_key_images: Vec<SpentKeyImage>,
+}
+
+/// Entry of the output histogram, see [`DaemonClient::get_output_histogram`](crate::DaemonClient::get_output_histogram).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct HistogramEntry {
+    pub amount: u64,
+    pub total_instances: u64,
+    pub unlocked_instances: u64,
+    pub recent_instances: u64,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct TransactionEntryR {
+    pub tx_hash: HashString<CryptoNoteHash>,
+    #[serde(default)]
+    pub as_hex: HashString<Vec<u8>>,
+    #[serde(default)]
+    pub pruned_as_hex: HashString<Vec<u8>>,
+    #[serde(default)]
+    pub prunable_as_hex: HashString<Vec<u8>>,
+    #[serde(default)]
+    pub prunable_hash: HashString<Vec<u8>>,
+    #[serde(default)]
+    pub as_json: String,
+    pub in_pool: bool,
+    pub double_spend_seen: bool,
+    #[serde(default)]
+    pub block_height: u64,
+    #[serde(default)]
+    pub confirmations: u64,
+    #[serde(default)]
+    pub block_timestamp: i64,
+    #[serde(default)]
+    pub output_indices: Vec<u64>,
+    #[serde(default)]
+    pub received_timestamp: i64,
+    #[serde(default)]
+    pub relayed: bool,
+}
+
+impl TryFrom<TransactionEntryR> for TransactionEntry {
+    type Error = anyhow::Error;
+
+    fn try_from(value: TransactionEntryR) -> anyhow::Result<Self> {
+        let (block_height, block_timestamp, received_timestamp) = if value.in_pool {
+            (
+                None,
+                None,
+                Utc.timestamp_opt(value.received_timestamp, 0).single(),
+            )
+        } else {
+            (
+                Some(value.block_height),
+                Utc.timestamp_opt(value.block_timestamp, 0).single(),
+                None,
+            )
+        };
+
+        Ok(Self {
+            tx_hash: value.tx_hash.0,
+            as_hex: value.as_hex.0,
+            pruned_as_hex: value.pruned_as_hex.0,
+            prunable_as_hex: value.prunable_as_hex.0,
+            prunable_hash: value.prunable_hash.0,
+            as_json: if value.as_json.is_empty() {
+                None
+            } else {
+                Some(serde_json::from_str(&value.as_json)?)
+            },
+            in_pool: value.in_pool,
+            double_spend_seen: value.double_spend_seen,
+            block_height,
+            block_timestamp,
+            confirmations: value.confirmations,
+            output_indices: value.output_indices,
+            received_timestamp,
+            relayed: value.relayed,
+        })
+    }
+}
+
+/// A transaction returned by `DaemonClient::get_transactions`.
+#[derive(Clone, Debug)]
+pub struct TransactionEntry {
+    pub tx_hash: CryptoNoteHash,
+    /// Full transaction blob. Empty if pruned data was requested.
+    pub as_hex: Vec<u8>,
+    /// Pruned part of the transaction blob. Only set if pruned data was requested.
+    pub pruned_as_hex: Vec<u8>,
+    /// Prunable part of the transaction blob. Only set if pruned data was requested and the daemon still has it.
+    pub prunable_as_hex: Vec<u8>,
+    /// Hash of the prunable part of the transaction.
+    pub prunable_hash: Vec<u8>,
+    /// Parsed transaction, if requested.
+    pub as_json: Option<TransactionJson>,
+    pub in_pool: bool,
+    pub double_spend_seen: bool,
+    /// Height of the block containing the transaction, `None` while it is in the pool.
+    pub block_height: Option<u64>,
+    /// Timestamp of the block containing the transaction, `None` while it is in the pool.
+    pub block_timestamp: Option<DateTime<Utc>>,
+    /// Number of blocks on top of the one containing the transaction. Always 0 on older daemons.
+    pub confirmations: u64,
+    /// Global indices of the transaction outputs. Empty while it is in the pool.
+    pub output_indices: Vec<u64>,
+    /// When the transaction entered the pool, `None` once it is mined.
+    pub received_timestamp: Option<DateTime<Utc>>,
+    /// Whether the transaction in the pool has been relayed to other nodes.
+    pub relayed: bool,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub(crate) struct TransactionsResponseR {
+    #[serde(default)]
+    pub txs: Vec<TransactionEntryR>,
+    #[serde(default)]
+    pub missed_tx: Vec<HashString<CryptoNoteHash>>,
+    pub untrusted: bool,
+    #[serde(default, rename = "txs_as_hex")]
+    _txs_as_hex: IgnoredAny,
+    #[serde(default, rename = "txs_as_json")]
+    _txs_as_json: IgnoredAny,
+}
+
+impl TryFrom<TransactionsResponseR> for TransactionsResponse {
+    type Error = anyhow::Error;
+
+    fn try_from(value: TransactionsResponseR) -> anyhow::Result<Self> {
+        Ok(Self {
+            txs: value
+                .txs
+                .into_iter()
+                .map(TryFrom::try_from)
+                .collect::<anyhow::Result<_>>()?,
+            missed_tx: value.missed_tx.into_iter().map(|v| v.0).collect(),
+            untrusted: value.untrusted,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TransactionsResponse {
+    /// Transactions found in the pool or in the blockchain.
+    pub txs: Vec<TransactionEntry>,
+    /// Requested transactions the daemon does not know about.
+    pub missed_tx: Vec<CryptoNoteHash>,
+    pub untrusted: bool,
+}
+
+/// Transaction in the daemon's JSON representation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionJson {
+    pub version: u64,
+    pub unlock_time: u64,
+    pub vin: Vec<TransactionInput>,
+    pub vout: Vec<TransactionOutput>,
+    pub extra: Vec<u8>,
+    /// RingCT data, absent on version 1 transactions.
+    #[serde(default)]
+    pub rct_signatures: Option<RctSignatures>,
+    /// Prunable RingCT data, i.e. range proofs and ring signatures, as the daemon's JSON. Absent on version 1 and coinbase transactions.
+    #[serde(default)]
+    pub rctsig_prunable: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub enum TransactionInput {
+    /// Coinbase input.
+    Gen { height: u64 },
+    /// Input spending previous outputs.
+    Key {
+        amount: u64,
+        key_offsets: Vec<u64>,
+        k_image: HashString<Vec<u8>>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransactionOutput {
+    pub amount: u64,
+    pub target: TransactionOutputTarget,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub enum TransactionOutputTarget {
+    Key(HashString<Vec<u8>>),
+    /// Output with a view tag, since hard fork version 15.
+    TaggedKey {
+        key: HashString<Vec<u8>>,
+        view_tag: HashString<Vec<u8>>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RctSignatures {
+    #[serde(rename = "type")]
+    pub rct_type: u8,
+    #[serde(rename = "txnFee", default)]
+    pub txn_fee: u64,
+    #[serde(rename = "ecdhInfo", default)]
+    pub ecdh_info: Vec<EcdhInfo>,
+    #[serde(rename = "outPk", default)]
+    pub out_pk: Vec<HashString<Vec<u8>>>,
+    /// Pseudo output commitments of the inputs. Only present here on older RingCT types, newer ones keep them in the prunable part.
+    #[serde(rename = "pseudoOuts", default)]
+    pub pseudo_outs: Vec<HashString<Vec<u8>>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct EcdhInfo {
+    /// Only present on older RingCT types.
+    #[serde(default)]
+    pub mask: Option<HashString<Vec<u8>>>,
+    pub amount: HashString<Vec<u8>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fee_tier_for_priority() {
+        let mut estimate = FeeEstimate {
+            fee: 7,
+            fees: vec![20, 80, 320, 4000],
+            quantization_mask: 10000,
+            untrusted: false,
+        };
+
+        assert_eq!(estimate.for_priority(TransferPriority::Default), 20);
+        assert_eq!(estimate.for_priority(TransferPriority::Unimportant), 20);
+        assert_eq!(estimate.for_priority(TransferPriority::Elevated), 80);
+        assert_eq!(estimate.for_priority(TransferPriority::Priority), 320);
+
+        estimate.fees.clear();
+        assert_eq!(estimate.for_priority(TransferPriority::Priority), 7);
+    }
+
+    #[test]
+    fn daemon_info_v0_18() {
+        // Response of a v0.18.3 mainnet node.
+        let rsp = r#"{
+            "adjusted_time": 1700000000,
+            "alt_blocks_count": 0,
+            "block_size_limit": 600000,
+            "block_size_median": 300000,
+            "block_weight_limit": 600000,
+            "block_weight_median": 300000,
+            "bootstrap_daemon_address": "",
+            "busy_syncing": false,
+            "credits": 0,
+            "cumulative_difficulty": 2305843009213693953,
+            "cumulative_difficulty_top64": 3,
+            "database_size": 1073741824,
+            "difficulty": 240000000000,
+            "difficulty_top64": 0,
+            "free_space": 18446744073709551615,
+            "grey_peerlist_size": 1000,
+            "height": 3000000,
+            "height_without_bootstrap": 3000000,
+            "incoming_connections_count": 0,
+            "mainnet": true,
+            "nettype": "mainnet",
+            "offline": false,
+            "outgoing_connections_count": 12,
+            "restricted": false,
+            "rpc_connections_count": 1,
+            "stagenet": false,
+            "start_time": 1699000000,
+            "status": "OK",
+            "synchronized": true,
+            "target": 120,
+            "target_height": 0,
+            "testnet": false,
+            "top_block_hash": "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            "top_hash": "",
+            "tx_count": 30000000,
+            "tx_pool_size": 20,
+            "untrusted": false,
+            "update_available": false,
+            "version": "0.18.3.1-release",
+            "was_bootstrap_ever_used": false,
+            "white_peerlist_size": 500,
+            "wide_cumulative_difficulty": "0x32000000000000001",
+            "wide_difficulty": "0x37e11d6000"
+        }"#;
+
+        let info = DaemonInfo::from(
+            serde_json::from_str::<MoneroResult<DaemonInfoR>>(rsp)
+                .unwrap()
+                .into_inner(),
+        );
+        assert_eq!(
+            info.cumulative_difficulty,
+            (3 << 64) | 0x2000_0000_0000_0001
+        );
+        assert_eq!(info.top_block_hash.as_bytes()[0], 1);
+        assert_eq!(info.version, "0.18.3.1-release");
+    }
+}
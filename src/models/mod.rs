@@ -0,0 +1,14 @@
+//! RPC request and response types.
+//!
+//! Types are grouped by the client that uses them:
+//! - [`common`] holds types shared by both clients, such as [`BlockHash`](common::BlockHash) and [`MoneroResult`](common::MoneroResult).
+//! - [`daemon`] holds types used by [`DaemonClient`](crate::DaemonClient) and [`RegtestDaemonClient`](crate::RegtestDaemonClient).
+//! - [`wallet`] holds types used by [`WalletClient`](crate::WalletClient).
+//!
+//! Every type is also re-exported at the crate root.
+
+pub mod common;
+pub mod daemon;
+pub mod wallet;
+
+pub use self::{common::*, daemon::*, wallet::*};
@@ -0,0 +1,729 @@
+//! Types used by [`WalletClient`](crate::WalletClient).
+
+use super::common::*;
+use crate::util::*;
+use chrono::prelude::*;
+use monero::{
+    cryptonote::hash::Hash as CryptoNoteHash,
+    util::address::{AddressType, PaymentId},
+    Address,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::{collections::HashMap, convert::TryFrom, num::NonZeroU64};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SubaddressBalanceData {
+    pub address: Address,
+    pub address_index: u64,
+    pub balance: u64,
+    pub label: String,
+    pub num_unspent_outputs: u64,
+    pub unlocked_balance: u64,
+    #[serde(default)]
+    pub account_index: u64,
+    /// Blocks until the whole balance is unlocked.
+    #[serde(default)]
+    pub blocks_to_unlock: u64,
+    /// Seconds until the whole balance is unlocked, for outputs locked until a timestamp.
+    #[serde(default)]
+    pub time_to_unlock: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct BalanceData {
+    pub balance: u64,
+    pub multisig_import_needed: bool,
+    #[serde(default)]
+    pub per_subaddress: Vec<SubaddressBalanceData>,
+    pub unlocked_balance: u64,
+    /// Blocks until the whole balance is unlocked.
+    #[serde(default)]
+    pub blocks_to_unlock: u64,
+    /// Seconds until the whole balance is unlocked, for outputs locked until a timestamp.
+    #[serde(default)]
+    pub time_to_unlock: u64,
+}
+
+/// Priority of a transfer. Variants are ordered by their RPC value, `Default` letting the wallet choose.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TransferPriority {
+    Default,
+    Unimportant,
+    Elevated,
+    Priority,
+}
+
+/// Result of [`WalletClient::transfer`](crate::WalletClient::transfer).
+///
+/// Whether the transaction was relayed cannot be told from this response: the wallet returns the same fields with or without `do_not_relay`,
+/// as `tx_metadata` is always requested, and fails the call if the daemon rejects the transaction.
+/// Use [`WalletClient::confirm_relayed`](crate::WalletClient::confirm_relayed) to check that it reached the pool.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransferData {
+    pub amount: u64,
+    pub fee: u64,
+    pub tx_blob: HashString<Vec<u8>>,
+    pub tx_hash: HashString<CryptoNoteHash>,
+    pub tx_key: HashString<Vec<u8>>,
+    pub tx_metadata: HashString<Vec<u8>>,
+    pub unsigned_txset: HashString<Vec<u8>>,
+    /// Set when the wallet is multisig.
+    #[serde(default)]
+    pub multisig_txset: HashString<Vec<u8>>,
+    /// Zero if the wallet does not report it.
+    #[serde(default)]
+    pub weight: u64,
+    /// Amount sent to each destination, in the order they were given.
+    #[serde(default)]
+    pub amounts_by_dest: AmountList,
+    /// Key images of the outputs spent by the transaction.
+    #[serde(default)]
+    pub spent_key_images: KeyImageList,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AmountList {
+    pub amounts: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyImageList {
+    pub key_images: Vec<HashString<Vec<u8>>>,
+}
+
+impl TransferData {
+    /// Transaction fee in XMR.
+    pub fn fee_xmr(&self) -> f64 {
+        self.fee as f64 / PICONERO_PER_XMR as f64
+    }
+
+    /// Transaction fee in piconero per byte of transaction weight, or `None` if the wallet did not report the weight.
+    pub fn fee_per_byte(&self) -> Option<f64> {
+        if self.weight == 0 {
+            return None;
+        }
+
+        Some(self.fee as f64 / self.weight as f64)
+    }
+}
+
+/// Transactions created by [`WalletClient::transfer_split`](crate::WalletClient::transfer_split), one entry per transaction in each list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransferSplitData {
+    pub tx_hash_list: Vec<HashString<CryptoNoteHash>>,
+    pub tx_key_list: Vec<HashString<Vec<u8>>>,
+    pub amount_list: Vec<u64>,
+    pub fee_list: Vec<u64>,
+    #[serde(default)]
+    pub weight_list: Vec<u64>,
+    pub tx_blob_list: Vec<HashString<Vec<u8>>>,
+    pub tx_metadata_list: Vec<HashString<Vec<u8>>>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub unsigned_txset: Option<HashString<Vec<u8>>>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub multisig_txset: Option<HashString<Vec<u8>>>,
+    /// Amounts sent to each destination by each transaction.
+    #[serde(default)]
+    pub amounts_by_dest_list: Vec<AmountList>,
+    #[serde(default)]
+    pub spent_key_images_list: Vec<KeyImageList>,
+}
+
+/// Transactions created by [`WalletClient::sweep_all`](crate::WalletClient::sweep_all), one entry per transaction in each list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SweepAllData {
+    pub tx_hash_list: Vec<HashString<CryptoNoteHash>>,
+    pub amount_list: Vec<u64>,
+    pub fee_list: Vec<u64>,
+    #[serde(default)]
+    pub tx_key_list: Vec<HashString<Vec<u8>>>,
+    #[serde(default)]
+    pub weight_list: Vec<u64>,
+    #[serde(default)]
+    pub tx_blob_list: Vec<HashString<Vec<u8>>>,
+    #[serde(default)]
+    pub tx_metadata_list: Vec<HashString<Vec<u8>>>,
+    /// Set when the wallet is view-only, to be signed with [`WalletClient::sign_transfer`](crate::WalletClient::sign_transfer).
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub unsigned_txset: Option<HashString<Vec<u8>>>,
+    /// Set when the wallet is multisig.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub multisig_txset: Option<HashString<Vec<u8>>>,
+    #[serde(default)]
+    pub spent_key_images_list: Vec<KeyImageList>,
+}
+
+/// Transaction created by [`WalletClient::sweep_single`](crate::WalletClient::sweep_single).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SweepSingleData {
+    pub tx_hash: HashString<CryptoNoteHash>,
+    pub tx_key: HashString<Vec<u8>>,
+    pub amount: u64,
+    pub fee: u64,
+    #[serde(default)]
+    pub weight: u64,
+    pub tx_blob: HashString<Vec<u8>>,
+    pub tx_metadata: HashString<Vec<u8>>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub unsigned_txset: Option<HashString<Vec<u8>>>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub multisig_txset: Option<HashString<Vec<u8>>>,
+    #[serde(default)]
+    pub spent_key_images: KeyImageList,
+}
+
+/// Transactions created by [`WalletClient::sweep_dust`](crate::WalletClient::sweep_dust), one entry per transaction in each list.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SweepDustData {
+    #[serde(default)]
+    pub tx_hash_list: Vec<HashString<CryptoNoteHash>>,
+    /// Only set if tx keys were requested.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub tx_key_list: Option<Vec<HashString<Vec<u8>>>>,
+    #[serde(default)]
+    pub amount_list: Vec<u64>,
+    #[serde(default)]
+    pub fee_list: Vec<u64>,
+    #[serde(default)]
+    pub weight_list: Vec<u64>,
+    #[serde(default)]
+    pub tx_blob_list: Vec<HashString<Vec<u8>>>,
+    #[serde(default)]
+    pub tx_metadata_list: Vec<HashString<Vec<u8>>>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub unsigned_txset: Option<HashString<Vec<u8>>>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub multisig_txset: Option<HashString<Vec<u8>>>,
+    #[serde(default)]
+    pub spent_key_images_list: Vec<KeyImageList>,
+}
+
+/// The wallet sends an empty string or list for values it did not create.
+fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default + PartialEq,
+{
+    T::deserialize(deserializer).map(|v| Some(v).filter(|v| *v != T::default()))
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SubaddressData {
+    pub address: Address,
+    pub address_index: u64,
+    pub label: String,
+    pub used: bool,
+}
+
+/// Index of a subaddress. Sorts by `major` (account), then `minor`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct SubaddressIndex {
+    pub major: u64,
+    pub minor: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct Payment {
+    pub payment_id: HashString<PaymentId>,
+    pub tx_hash: HashString<CryptoNoteHash>,
+    pub amount: u64,
+    pub block_height: u64,
+    pub unlock_time: u64,
+    /// Whether the payment is still locked, either by `unlock_time` or because it has too few confirmations.
+    #[serde(default)]
+    pub locked: bool,
+    pub subaddr_index: SubaddressIndex,
+    pub address: Address,
+}
+
+impl Payment {
+    /// Find the integrated address among `addresses` that this payment was sent to,
+    /// i.e. the one built from the receiving address and embedding this payment's ID.
+    pub fn integrated_address<'a, I>(&self, addresses: I) -> Option<&'a Address>
+    where
+        I: IntoIterator<Item = &'a Address>,
+    {
+        addresses.into_iter().find(|address| {
+            address.addr_type == AddressType::Integrated(self.payment_id.0)
+                && address.public_spend == self.address.public_spend
+                && address.public_view == self.address.public_view
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AddressData {
+    pub address: Address,
+    pub addresses: Vec<SubaddressData>,
+}
+
+/// Unlock time of the outputs of a transaction.
+///
+/// The protocol interprets values below 500,000,000 as block heights and larger ones as UNIX timestamps.
+/// Only heights are supported, so a timestamp passed by mistake is rejected instead of locking funds for decades.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(into = "u64", try_from = "u64")]
+pub enum UnlockTime {
+    /// Outputs are spendable as soon as the usual 10 confirmations are reached.
+    #[default]
+    None,
+    /// Outputs are locked until the chain reaches this block height.
+    Blocks(u64),
+}
+
+impl UnlockTime {
+    /// Values from this one on are timestamps, not block heights.
+    pub const MAX_BLOCK_NUMBER: u64 = 500_000_000;
+}
+
+impl From<UnlockTime> for u64 {
+    fn from(value: UnlockTime) -> Self {
+        match value {
+            UnlockTime::None => 0,
+            UnlockTime::Blocks(height) => height,
+        }
+    }
+}
+
+impl TryFrom<u64> for UnlockTime {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u64) -> anyhow::Result<Self> {
+        match value {
+            0 => Ok(UnlockTime::None),
+            height if height < Self::MAX_BLOCK_NUMBER => Ok(UnlockTime::Blocks(height)),
+            timestamp => Err(anyhow::anyhow!(
+                "Unlock time {} is a timestamp, only block heights are supported",
+                timestamp
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct TransferOptions {
+    pub account_index: Option<u64>,
+    pub subaddr_indices: Option<Vec<u64>>,
+    pub mixin: Option<u64>,
+    pub ring_size: Option<u64>,
+    pub unlock_time: Option<UnlockTime>,
+    pub payment_id: Option<PaymentId>,
+    pub do_not_relay: Option<bool>,
+}
+
+/// Options for [`WalletClient::sweep_all`](crate::WalletClient::sweep_all) and [`WalletClient::sweep_single`](crate::WalletClient::sweep_single).
+/// `sweep_single` ignores `subaddr_indices` and `below_amount`.
+#[derive(Clone, Debug, Default)]
+pub struct SweepAllOptions {
+    /// Only sweep these subaddresses of the account. Sweeps every subaddress if `None`.
+    pub subaddr_indices: Option<Vec<u64>>,
+    pub priority: Option<TransferPriority>,
+    pub ring_size: Option<u64>,
+    pub unlock_time: Option<UnlockTime>,
+    pub payment_id: Option<PaymentId>,
+    pub do_not_relay: Option<bool>,
+    /// Only sweep outputs worth less than this many piconero.
+    pub below_amount: Option<u64>,
+}
+
+/// Whether the wallet connects to its daemon over SSL.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SslSupport {
+    /// Use SSL if the daemon supports it.
+    Autodetect,
+    Enabled,
+    Disabled,
+}
+
+/// Options for [`WalletClient::set_daemon`](crate::WalletClient::set_daemon).
+#[derive(Clone, Debug, Default)]
+pub struct SetDaemonOptions {
+    /// Whether the daemon is trusted, which enables operations leaking information to it. Defaults to `false`.
+    pub trusted: Option<bool>,
+    pub ssl_support: Option<SslSupport>,
+    /// Only accept daemon certificates with one of these SHA-256 fingerprints.
+    pub ssl_allowed_fingerprints: Vec<String>,
+    /// Accept any daemon certificate, defeating certificate checks. Only sensible together with `ssl_allowed_fingerprints`.
+    pub ssl_allow_any_cert: bool,
+    pub ssl_ca_file: Option<String>,
+    /// Daemon RPC login.
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GotAccount {
+    pub account_index: u64,
+    pub balance: u64,
+    pub base_address: monero::Address,
+    pub label: Option<String>,
+    pub tag: Option<String>,
+    pub unlocked_balance: u64,
+}
+
+/// Tag grouping accounts, see [`WalletClient::tag_accounts`](crate::WalletClient::tag_accounts).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct AccountTag {
+    pub tag: String,
+    /// Description set with [`WalletClient::set_account_tag_description`](crate::WalletClient::set_account_tag_description).
+    pub label: String,
+    /// Indices of the tagged accounts.
+    pub accounts: Vec<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GetAccountsData {
+    pub subaddress_accounts: Vec<GotAccount>,
+    pub total_balance: u64,
+    pub total_unlocked_balance: u64,
+}
+
+/// Category of a transfer, as used by `get_transfers` and in the `type` field of [`GotTransfer`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GetTransfersCategory {
+    /// Incoming transfer confirmed in a block.
+    In,
+    /// Outgoing transfer confirmed in a block.
+    Out,
+    /// Outgoing transfer relayed to the pool but not yet mined.
+    Pending,
+    /// Outgoing transfer rejected by the daemon.
+    Failed,
+    /// Incoming transfer in the pool.
+    Pool,
+    /// Coinbase output from a block mined by the wallet. Only appears as a transfer type; `get_transfers` lists these under `In`.
+    Block,
+}
+
+impl From<GetTransfersCategory> for &'static str {
+    fn from(value: GetTransfersCategory) -> Self {
+        use GetTransfersCategory::*;
+
+        match value {
+            In => "in",
+            Out => "out",
+            Pending => "pending",
+            Failed => "failed",
+            Pool => "pool",
+            Block => "block",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct GetTransfersSelector<T> {
+    pub category_selector: HashMap<GetTransfersCategory, bool>,
+    /// Filter transfers by block height.
+    pub filter_by_height: Option<T>,
+    /// Index of the account to query for transfers. (defaults to 0)
+    pub account_index: Option<u64>,
+    /// Return transfers for all accounts. When set, `account_index` is ignored.
+    pub all_accounts: bool,
+    /// List of subaddress indices to query for transfers. (Defaults to empty - all indices)
+    pub subaddr_indices: Option<Vec<u64>>,
+}
+
+impl<T> Default for GetTransfersSelector<T> {
+    fn default() -> Self {
+        Self {
+            category_selector: Default::default(),
+            filter_by_height: Default::default(),
+            account_index: Default::default(),
+            all_accounts: Default::default(),
+            subaddr_indices: Default::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TransferHeight {
+    Confirmed(NonZeroU64),
+    InPool,
+}
+
+impl<'de> Deserialize<'de> for TransferHeight {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let h = u64::deserialize(deserializer)?;
+
+        Ok({
+            if let Some(h) = NonZeroU64::new(h) {
+                TransferHeight::Confirmed(h)
+            } else {
+                TransferHeight::InPool
+            }
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GotTransfer {
+    /// Public address of the transfer.
+    pub address: Address,
+    /// Amount transferred. For outgoing transfers, this is the sum of `destinations`, excluding `fee` and change.
+    pub amount: u64,
+    /// Amounts of the individual outputs of an incoming transfer to the same subaddress.
+    #[serde(default)]
+    pub amounts: Vec<u64>,
+    /// Number of block mined since the block containing this transaction (or block height at which the transaction should be added to a block if not yet confirmed).
+    /// Missing from older wallets, in which case it is 0.
+    #[serde(default)]
+    pub confirmations: u64,
+    /// Recipients of an outgoing transfer. Empty for incoming transfers.
+    #[serde(default)]
+    pub destinations: Vec<TransferRecipient>,
+    /// True if the key image(s) for the transfer have been seen before.
+    pub double_spend_seen: bool,
+    /// Transaction fee for this transfer.
+    pub fee: u64,
+    /// Height of the first block that confirmed this transfer (0 if not mined yet).
+    pub height: TransferHeight,
+    /// Note about this transfer.
+    pub note: String,
+    /// Payment ID for this transfer.
+    pub payment_id: HashString<PaymentId>,
+    /// JSON object containing the major & minor subaddress index.
+    pub subaddr_index: SubaddressIndex,
+    /// Subaddresses an outgoing transfer spent from.
+    #[serde(default)]
+    pub subaddr_indices: Vec<SubaddressIndex>,
+    /// Estimation of the confirmations needed for the transaction to be included in a block.
+    /// Missing from older wallets, in which case it is 0.
+    #[serde(default)]
+    pub suggested_confirmations_threshold: u64,
+    /// POSIX timestamp for when this transfer was first confirmed in a block (or timestamp submission if not mined yet).
+    #[serde(with = "chrono::serde::ts_seconds")]
+    pub timestamp: DateTime<Utc>,
+    /// Transaction ID for this transfer.
+    pub txid: HashString<Vec<u8>>,
+    /// Type of transfer.
+    #[serde(rename = "type")]
+    pub transfer_type: GetTransfersCategory,
+    /// Number of blocks until transfer is safely spendable.
+    pub unlock_time: u64,
+    /// Whether the transfer is still locked, either by `unlock_time` or because it has too few confirmations.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+impl GotTransfer {
+    /// Effect of the transfer on the wallet's balance: positive for incoming transfers,
+    /// negative `amount + fee` for outgoing ones and zero for failed ones, which never left the wallet.
+    pub fn net_amount(&self) -> i128 {
+        match self.transfer_type {
+            GetTransfersCategory::In | GetTransfersCategory::Pool | GetTransfersCategory::Block => {
+                i128::from(self.amount)
+            }
+            GetTransfersCategory::Out | GetTransfersCategory::Pending => {
+                -(i128::from(self.amount) + i128::from(self.fee))
+            }
+            GetTransfersCategory::Failed => 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransferRecipient {
+    pub address: Address,
+    pub amount: u64,
+}
+
+/// Description of one transaction of an unsigned transaction set.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TransferDescription {
+    /// Sum of the inputs spent by the transaction.
+    pub amount_in: u64,
+    /// Sum of the outputs created by the transaction, including change.
+    pub amount_out: u64,
+    pub recipients: Vec<TransferRecipient>,
+    pub change_amount: u64,
+    /// Empty if the transaction has no change.
+    pub change_address: String,
+    pub fee: u64,
+    pub payment_id: String,
+    pub ring_size: u64,
+    pub unlock_time: u64,
+    pub dummy_outputs: u64,
+    pub extra: HashString<Vec<u8>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SignedTransferOutput {
+    pub signed_txset: Vec<u8>,
+    pub tx_hash_list: Vec<CryptoNoteHash>,
+    pub tx_raw_list: Vec<Vec<u8>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct SignedKeyImage {
+    pub key_image: Vec<u8>,
+    pub signature: Vec<u8>,
+}
+
+/// Result of [`WalletClient::export_key_images`](crate::WalletClient::export_key_images).
+#[derive(Clone, Debug)]
+pub struct ExportedKeyImages {
+    /// Position of the first exported key image in the wallet's key image list; pass it back to
+    /// [`WalletClient::import_key_images`](crate::WalletClient::import_key_images).
+    pub offset: u64,
+    pub signed_key_images: Vec<SignedKeyImage>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct KeyImageImportResponse {
+    pub height: u64,
+    /// Amount spent from key images.
+    pub spent: u64,
+    /// Amount still available from key images.
+    pub unspent: u64,
+}
+
+/// Signature proving that the wallet sent or received a transaction.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct TxProof {
+    pub txid: HashString<CryptoNoteHash>,
+    pub signature: String,
+}
+
+/// Wallet created by [`WalletClient::generate_from_keys`](crate::WalletClient::generate_from_keys).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct GenerateFromKeysData {
+    pub address: Address,
+    /// Human-readable description of the created wallet, e.g. whether it is view-only.
+    pub info: String,
+}
+
+/// Wallet created by [`WalletClient::restore_deterministic_wallet`](crate::WalletClient::restore_deterministic_wallet).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct RestoreDeterministicWalletData {
+    pub address: Address,
+    pub info: String,
+    /// Mnemonic seed of the wallet, in the current format even if an old one was given.
+    pub seed: String,
+    /// Whether the given seed was in a deprecated format.
+    pub was_deprecated: bool,
+}
+
+/// Result of [`WalletClient::check_tx_proof`](crate::WalletClient::check_tx_proof).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct CheckTxProof {
+    /// Whether the signature is valid. The other fields are only meaningful if it is.
+    pub good: bool,
+    /// Amount received by the address in the transaction, in piconero. Zero if the proof is valid but nothing was received.
+    #[serde(default)]
+    pub received: u64,
+    #[serde(default)]
+    pub in_pool: bool,
+    /// Zero while the transaction is in the pool.
+    #[serde(default)]
+    pub confirmations: u64,
+}
+
+/// Proofs for a set of transactions plus a reserve proof, see [`WalletClient::export_proof_bundle`](crate::WalletClient::export_proof_bundle).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict", serde(deny_unknown_fields))]
+pub struct ProofBundle {
+    /// Address the tx proofs were made towards.
+    pub address: Address,
+    /// Message all proofs are signed over.
+    pub message: String,
+    /// Wallet height when the bundle was made.
+    pub height: u64,
+    pub created_at: DateTime<Utc>,
+    pub tx_proofs: Vec<TxProof>,
+    /// Reserve proof of the wallet's whole balance.
+    pub reserve_proof: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn transfer_data_without_weight() {
+        let mut rsp = json!({
+            "amount": 1000,
+            "fee": 300,
+            "tx_blob": "",
+            "tx_hash": "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20",
+            "tx_key": "",
+            "tx_metadata": "",
+            "unsigned_txset": "",
+        });
+
+        let data = serde_json::from_value::<TransferData>(rsp.clone()).unwrap();
+        assert_eq!(data.weight, 0);
+        assert_eq!(data.fee_per_byte(), None);
+
+        rsp["weight"] = 150.into();
+        let data = serde_json::from_value::<TransferData>(rsp).unwrap();
+        assert_eq!(data.fee_per_byte(), Some(2.0));
+    }
+
+    #[test]
+    fn net_amount_by_transfer_type() {
+        let spend = monero::PrivateKey::from_slice(&[2; 32]).unwrap();
+        let view = monero::PrivateKey::from_slice(&[1; 32]).unwrap();
+        let address = Address::standard(
+            monero::Network::Mainnet,
+            monero::PublicKey::from_private_key(&spend),
+            monero::PublicKey::from_private_key(&view),
+        );
+        let transfer = |transfer_type: &str| {
+            serde_json::from_value::<GotTransfer>(json!({
+                "address": address.to_string(),
+                "amount": 1000,
+                "destinations": [{"address": address.to_string(), "amount": 1000}],
+                "double_spend_seen": false,
+                "fee": 30,
+                "height": 0,
+                "note": "",
+                "payment_id": "0000000000000000",
+                "subaddr_index": {"major": 0, "minor": 0},
+                "timestamp": 1_600_000_000,
+                "txid": "01",
+                "type": transfer_type,
+                "unlock_time": 0,
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(transfer("in").net_amount(), 1000);
+        assert_eq!(transfer("pool").net_amount(), 1000);
+        assert_eq!(transfer("block").net_amount(), 1000);
+        assert_eq!(transfer("out").net_amount(), -1030);
+        assert_eq!(transfer("pending").net_amount(), -1030);
+        assert_eq!(transfer("failed").net_amount(), 0);
+    }
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Deserializer, Serialize};
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
 pub trait HashType: Sized {
     fn bytes(&self) -> &[u8];
@@ -24,14 +27,33 @@ hash_type_impl!(monero::cryptonote::hash::Hash);
 
 impl HashType for Vec<u8> {
     fn bytes(&self) -> &[u8] {
-        &*self
+        self
     }
     fn from_str(v: &str) -> anyhow::Result<Self> {
         Ok(hex::decode(v)?)
     }
 }
 
-#[derive(Clone, Debug)]
+/// Canonical textual form of a hash or binary blob: lowercase hex without a `0x` prefix.
+///
+/// Some hash types, such as [`CryptoNoteHash`](monero::cryptonote::hash::Hash), abbreviate their `Display` output,
+/// so wrap them in `HashString` whenever the full value has to be printed, parsed or sent over RPC.
+///
+/// ```
+/// use monero_rpc::{BlockHash, HashString};
+///
+/// let s = "0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20";
+///
+/// let hash = s.parse::<HashString<BlockHash>>().unwrap();
+/// assert_eq!(hash.to_string(), s);
+/// assert_eq!(s.to_uppercase().parse::<HashString<BlockHash>>().unwrap().to_string(), s);
+///
+/// let json = serde_json::to_string(&hash).unwrap();
+/// assert_eq!(json, format!("\"{}\"", s));
+/// assert_eq!(serde_json::from_str::<BlockHash>(&json).unwrap(), hash.0);
+/// assert_eq!(serde_json::to_string(&hash.0).unwrap(), json);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct HashString<T>(pub T);
 
 impl<T> Display for HashString<T>
@@ -43,7 +65,18 @@ where
     }
 }
 
-impl<'a, T> Serialize for HashString<T>
+impl<T> FromStr for HashString<T>
+where
+    T: HashType,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        T::from_str(s).map(Self)
+    }
+}
+
+impl<T> Serialize for HashString<T>
 where
     T: HashType,
 {
@@ -63,7 +96,8 @@ where
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        Ok(Self(T::from_str(&s).map_err(serde::de::Error::custom)?))
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
     }
 }
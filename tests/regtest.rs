@@ -0,0 +1,160 @@
+//! Tests against a live regtest setup. They are ignored by default; start `monerod --regtest --offline --fixed-difficulty 1`
+//! and a `monero-wallet-rpc` with a freshly created wallet attached to it, then run
+//! `MONERO_DAEMON_URL=http://127.0.0.1:18081 MONERO_WALLET_URL=http://127.0.0.1:18083 cargo test -- --ignored --test-threads=1`.
+//! The tests share the wallet and check its balance, so they must not run concurrently.
+
+use monero::{cryptonote::hash::Hash as CryptoNoteHash, Address, PrivateKey, PublicKey};
+use monero_rpc::{
+    GetTransfersCategory, RegtestDaemonClient, RpcClient, TransferOptions, TransferPriority,
+    WalletClient,
+};
+use std::{collections::HashMap, env};
+
+fn clients() -> (RegtestDaemonClient, WalletClient) {
+    let daemon = env::var("MONERO_DAEMON_URL").expect("MONERO_DAEMON_URL is not set");
+    let wallet = env::var("MONERO_WALLET_URL").expect("MONERO_WALLET_URL is not set");
+
+    (
+        RpcClient::with_network(daemon, monero::Network::Mainnet)
+            .daemon()
+            .regtest(),
+        RpcClient::with_network(wallet, monero::Network::Mainnet).wallet(),
+    )
+}
+
+/// Address outside the wallet, to mine blocks without changing the wallet's balance.
+fn foreign_address() -> Address {
+    let view = PrivateKey::from_slice(&[1; 32]).unwrap();
+    let spend = PrivateKey::from_slice(&[2; 32]).unwrap();
+
+    Address::standard(
+        monero::Network::Mainnet,
+        PublicKey::from_private_key(&spend),
+        PublicKey::from_private_key(&view),
+    )
+}
+
+#[tokio::test]
+#[ignore]
+async fn key_images_round_trip() {
+    let (daemon, wallet) = clients();
+    let address = wallet.get_address(0, None).await.unwrap().address;
+
+    daemon.generate_blocks_to_unlock(address).await.unwrap();
+    wallet.refresh(None).await.unwrap();
+
+    let exported = wallet.export_key_images(true).await.unwrap();
+    assert_eq!(exported.offset, 0);
+    assert!(!exported.signed_key_images.is_empty());
+
+    // A wallet holding its spend key knows all its key images, so a partial export starts past the last one.
+    let partial = wallet.export_key_images(false).await.unwrap();
+    assert_eq!(partial.offset, exported.signed_key_images.len() as u64);
+    assert!(partial.signed_key_images.is_empty());
+
+    let balance = wallet.get_balance(0, None).await.unwrap();
+    let imported = wallet
+        .import_key_images(exported.signed_key_images, exported.offset)
+        .await
+        .unwrap();
+    assert_eq!(imported.unspent, balance.balance);
+}
+
+#[tokio::test]
+#[ignore]
+async fn transfer_between_accounts_balances() {
+    let (daemon, wallet) = clients();
+    let address = wallet.get_address(0, None).await.unwrap().address;
+
+    daemon.generate_blocks_to_unlock(address).await.unwrap();
+    wallet.refresh(None).await.unwrap();
+
+    let (account, recipient) = wallet.create_account(None).await.unwrap();
+    let sender_before = wallet.get_balance(0, None).await.unwrap().balance;
+    let recipient_before = wallet.get_balance(account, None).await.unwrap().balance;
+
+    let amount = 1_000_000_000;
+    let sent = wallet
+        .transfer(
+            HashMap::from([(recipient, amount)]),
+            Some(TransferPriority::Default),
+            TransferOptions {
+                account_index: Some(0),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(sent.amount, amount);
+
+    daemon
+        .generate_blocks(1, foreign_address(), None)
+        .await
+        .unwrap();
+    wallet.refresh(None).await.unwrap();
+
+    let txid = sent.tx_hash.0;
+    let out = wallet.get_transfer(txid, Some(0)).await.unwrap().unwrap();
+    assert_eq!(out.transfer_type, GetTransfersCategory::Out);
+    assert_eq!(out.amount, amount);
+    assert_eq!(out.fee, sent.fee);
+    assert_eq!(
+        out.destinations.iter().map(|d| d.amount).sum::<u64>(),
+        out.amount
+    );
+    assert_eq!(out.net_amount(), -i128::from(amount + sent.fee));
+
+    let incoming = wallet
+        .get_transfer(txid, Some(account))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(incoming.transfer_type, GetTransfersCategory::In);
+    assert_eq!(incoming.net_amount(), i128::from(amount));
+
+    // The change went back to account 0, so its balance only dropped by what left it.
+    let sender_after = wallet.get_balance(0, None).await.unwrap().balance;
+    let recipient_after = wallet.get_balance(account, None).await.unwrap().balance;
+    assert_eq!(
+        i128::from(sender_after) - i128::from(sender_before),
+        out.net_amount()
+    );
+    assert_eq!(
+        i128::from(recipient_after) - i128::from(recipient_before),
+        incoming.net_amount()
+    );
+}
+
+#[tokio::test]
+#[ignore]
+async fn check_tx_keys_against_wallet() {
+    let (daemon, wallet) = clients();
+    let address = wallet.get_address(0, None).await.unwrap().address;
+
+    daemon.generate_blocks_to_unlock(address).await.unwrap();
+    wallet.refresh(None).await.unwrap();
+
+    let (_, recipient) = wallet.create_account(None).await.unwrap();
+    let amount = 1_000_000_000;
+    let sent = wallet
+        .transfer(
+            HashMap::from([(recipient, amount)]),
+            None,
+            TransferOptions::default(),
+        )
+        .await
+        .unwrap();
+    let tx_key = CryptoNoteHash::from_slice(&sent.tx_key.0);
+
+    let checked = wallet
+        .check_tx_keys(vec![
+            (sent.tx_hash.0, tx_key, recipient),
+            (CryptoNoteHash::from_slice(&[1; 32]), tx_key, recipient),
+        ])
+        .await
+        .unwrap();
+    assert_eq!(checked.len(), 2);
+    let (confirmations, in_pool, received) = *checked[0].as_ref().unwrap();
+    assert_eq!((confirmations, in_pool, received), (0, true, amount));
+    assert!(checked[1].is_err());
+}